@@ -1,12 +1,13 @@
 use open_dart::client::OpenDartApi;
 use open_dart::endpoints::company;
+use open_dart::endpoints::identifiers::CorpCode;
 
 #[tokio::main]
 async fn main() {
     let api = OpenDartApi::default();
 
     let company_params = company::ParamsBuilder::default()
-        .corp_code("00126380")
+        .corp_code(CorpCode::new("00126380").expect("Invalid corp_code"))
         .build()
         .expect("Failed to build CompanyRequestParams");
 
@@ -0,0 +1,27 @@
+//! Example for calling the `fnltt_multi_acnt` endpoint.
+
+use open_dart::client::OpenDartApi;
+use open_dart::endpoints::codes::ReprtCode;
+use open_dart::endpoints::fnltt_multi_acnt;
+use open_dart::endpoints::identifiers::{BsnsYear, CorpCode};
+
+#[tokio::main]
+async fn main() {
+    let api = OpenDartApi::default();
+
+    let params = fnltt_multi_acnt::ParamsBuilder::default()
+        .corp_codes(
+            ["00126380", "00164779"]
+                .map(|corp_code| CorpCode::new(corp_code).expect("Invalid corp_code")),
+        )
+        .bsns_year(BsnsYear::new("2023").expect("Invalid bsns_year"))
+        .reprt_code(ReprtCode::Annual)
+        .build()
+        .expect("Failed to build FnlttMultiAcntRequestParams");
+    let response = api
+        .get_fnltt_multi_acnt(params)
+        .await
+        .expect("Failed to get fnltt_multi_acnt");
+
+    println!("{:#?}", response);
+}
@@ -0,0 +1,24 @@
+//! Example for calling the `fnltt_singl_acnt` endpoint.
+
+use open_dart::client::OpenDartApi;
+use open_dart::endpoints::codes::ReprtCode;
+use open_dart::endpoints::fnltt_singl_acnt;
+use open_dart::endpoints::identifiers::{BsnsYear, CorpCode};
+
+#[tokio::main]
+async fn main() {
+    let api = OpenDartApi::default();
+
+    let params = fnltt_singl_acnt::ParamsBuilder::default()
+        .corp_code(CorpCode::new("00126380").expect("Invalid corp_code"))
+        .bsns_year(BsnsYear::new("2023").expect("Invalid bsns_year"))
+        .reprt_code(ReprtCode::Annual)
+        .build()
+        .expect("Failed to build FnlttSinglAcntRequestParams");
+    let response = api
+        .get_fnltt_singl_acnt(params)
+        .await
+        .expect("Failed to get fnltt_singl_acnt");
+
+    println!("{:#?}", response);
+}
@@ -1,7 +1,9 @@
 //! Example for calling the `list` endpoint.
 
 use open_dart::client::OpenDartApi;
+use open_dart::endpoints::codes::{FsDiv, ReprtCode};
 use open_dart::endpoints::fnltt_singl_acnt_all;
+use open_dart::endpoints::identifiers::{BsnsYear, CorpCode};
 use tracing_subscriber::EnvFilter;
 
 #[tokio::main]
@@ -15,10 +17,10 @@ async fn main() {
     let api = OpenDartApi::default();
 
     let params = fnltt_singl_acnt_all::ParamsBuilder::default()
-        .corp_code("00126380")
-        .bsns_year("2023")
-        .reprt_code("11011")
-        .fs_div("CFS")
+        .corp_code(CorpCode::new("00126380").expect("Invalid corp_code"))
+        .bsns_year(BsnsYear::new("2023").expect("Invalid bsns_year"))
+        .reprt_code(ReprtCode::Annual)
+        .fs_div(FsDiv::Consolidated)
         .build()
         .expect("Failed to build FnlttSinglAcntAllRequestParams");
     let response = api
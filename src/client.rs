@@ -1,5 +1,5 @@
 use crate::endpoints::base::ResponseBody;
-use crate::endpoints::{OpenDartResponse, ResponseCheck};
+use crate::endpoints::{Message, OpenDartResponse, ResponseCheck};
 use crate::error::{OpenDartError, ResponseError};
 use bytes::Bytes;
 use derive_builder::Builder;
@@ -9,6 +9,9 @@ use serde::de::DeserializeOwned;
 use serde::Serialize;
 use std::fmt::Display;
 
+/// Local file header signature of a zip archive
+const ZIP_SIGNATURE: &[u8] = b"PK\x03\x04";
+
 #[derive(Debug)]
 pub struct OpenDartApi {
     client: reqwest::Client,
@@ -82,18 +85,13 @@ impl OpenDartApi {
     }
 
     #[tracing::instrument(skip(self))]
-    pub(crate) async fn get_zip<U>(&self, url: U) -> Result<Bytes, OpenDartError>
+    pub(crate) async fn get_zip<U, P>(&self, url: U, params: P) -> Result<Bytes, OpenDartError>
     where
         U: Display + IntoUrl + std::fmt::Debug,
+        P: Serialize + std::fmt::Debug + validator::Validate,
     {
-        #[derive(Debug, Serialize)]
-        struct Params {
-            crtfc_key: String,
-        }
-        let params = Params {
-            crtfc_key: std::env::var("OPEN_DART_API_KEY")
-                .expect("OPEN_DART_API_KEY must be set as an environment variable"),
-        };
+        params.validate()?;
+
         let request = self.client.get(url).query(&params).build()?;
 
         let response = self.client.execute(request).await?;
@@ -111,6 +109,18 @@ impl OpenDartApi {
             .await
             .inspect_err(|_e| tracing::error!("Failed to parse response body as bytes"))?;
 
+        // OpenDart responds with an XML message instead of a zip file
+        // when the request is not successful (e.g. no document for the given `rcept_no`)
+        if !bytes.starts_with(ZIP_SIGNATURE) {
+            let text = std::str::from_utf8(&bytes).inspect_err(|_e| {
+                tracing::error!("Failed to parse response body as text");
+            })?;
+            let message = quick_xml::de::from_str::<Message>(text).inspect_err(|_e| {
+                tracing::error!(body = ?text, "Failed to deserialize response body");
+            })?;
+            message.is_success()?;
+        }
+
         Ok(bytes)
     }
 
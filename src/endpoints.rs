@@ -1,11 +1,102 @@
+pub mod accnut_adtor_nm_nd_adt_opinion;
+pub mod accnut_adtor_non_adt_servc_cncls_sttus;
+pub mod adt_servc_cncls_sttus;
+pub mod alot_matter;
+pub mod ast_inhtrf_etc_ptbk_opt;
 pub(crate) mod base;
+pub mod bd_rs;
+pub mod bdwt_is_decsn;
+pub mod bnk_mngt_pcbg;
+pub mod bnk_mngt_pcsp;
+pub mod bsn_inh_decsn;
+pub mod bsn_sp;
+pub mod bsn_trf_decsn;
+pub mod cmp_dv_decsn;
+pub mod cmp_dvmg_decsn;
+pub mod cmp_mg_decsn;
+pub mod cndl_capl_scrits_nrdmp_blce;
+pub mod codes;
 pub mod company;
+pub mod control;
 mod corp_code_meta;
-mod fnltt_multi_acnt;
+pub mod cprnd_nrdmp_blce;
+pub mod cr_decsn;
+pub mod ctrcvs_bgrq;
+pub mod cvbd_is_decsn;
+mod de;
+pub mod det_scrits_isu_acmslt;
+pub mod df_ocr;
+pub mod dilution;
+pub mod document;
+pub mod drctr_adt_all_mendng_sttus_gmtsck_confm_amount;
+pub mod ds_rs_ocr;
+pub mod dv_rs;
+pub mod elestock;
+pub mod emp_sttus;
+pub mod entrprs_bil_scrits_nrdmp_blce;
+pub mod estk_rs;
+pub mod exbd_is_decsn;
+pub mod exctv_sttus;
+pub mod extr_rs;
+pub mod fnltt_cmpny_indx;
+pub mod fnltt_multi_acnt;
+pub mod fnltt_singl_acnt;
 pub mod fnltt_singl_acnt_all;
+pub mod fnltt_singl_indx;
+pub mod fnltt_xbrl;
+pub mod fric_decsn;
+pub mod hmv_audit_all_sttus;
+pub mod hmv_audit_indvdl_by_sttus;
+pub mod holding;
+pub mod hyslr_chg_sttus;
+pub mod hyslr_sttus;
+pub mod identifiers;
+pub mod indvdl_by_pay;
+pub mod irds_sttus;
 pub mod list;
+pub mod lwst_lg;
 mod macros;
+pub mod majorstock;
+pub mod maturity;
+pub mod mg_rs;
+pub mod money;
+pub mod mrhl_sttus;
+pub mod new_capl_scrits_nrdmp_blce;
+pub mod otcpr_stk_invscr_inh_decsn;
+pub mod otcpr_stk_invscr_trf_decsn;
+pub mod otr_cpr_invstmnt_sttus;
+pub mod outcmpny_drctr_nd_change_sttus;
+pub mod ov_dlst;
+pub mod ov_dlst_decsn;
+pub mod ov_lst;
+pub mod ov_lst_decsn;
+pub mod pifric_decsn;
+pub mod piic_decsn;
+pub mod prvsrp_cptal_use_dtls;
+pub mod pssrp_cptal_use_dtls;
+pub mod registration;
+pub mod reported;
+pub mod restructuring;
+pub mod share_class;
+pub mod srtpd_psndbt_nrdmp_blce;
+pub mod stk_extr_decsn;
+pub mod stkdp_rs;
+pub mod stkrtbd_inh_decsn;
+pub mod stkrtbd_trf_decsn;
+pub mod stock_totqy_sttus;
+pub mod tesstk_acqs_dsps_sttus;
+pub mod tgast_inh_decsn;
+pub mod tgast_trf_decsn;
+pub mod transfer;
+pub mod trust_contract;
+pub mod tsstk_aq_decsn;
+pub mod tsstk_aq_trctr_cc_decsn;
+pub mod tsstk_aq_trctr_cns_decsn;
+pub mod tsstk_dp_decsn;
+pub mod unrst_exctv_mendng_sttus;
+pub mod wd_cocobd_is_decsn;
+pub mod xbrl_taxonomy;
 
 pub(crate) use base::ResponseCheck;
 
-pub use base::{Message, OpenDartResponse};
+pub use base::{Message, OpenDartResponse, ResponseBody};
@@ -0,0 +1,200 @@
+//! # 회계감사인의 명칭 및 감사의견
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS002&apiId=2020009>
+//!
+//! 정기보고서(사업, 분기, 반기보고서) 내에 회계감사인의 명칭 및 감사의견 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::{CorpCls, ReprtCode};
+use crate::endpoints::de::{optional_date, optional_text};
+use crate::endpoints::identifiers::{BsnsYear, CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use chrono::NaiveDate;
+use std::fmt;
+
+impl OpenDartApi {
+    pub async fn get_accnut_adtor_nm_nd_adt_opinion(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<AccnutAdtorNmNdAdtOpinion>>, OpenDartError> {
+        self.get(self.url("/api/accnutAdtorNmNdAdtOpinion.json"), args)
+            .await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    pub bsns_year: BsnsYear,
+    pub reprt_code: ReprtCode,
+);
+
+json_body!(AccnutAdtorNmNdAdtOpinion {
+    pub list: Vec<AccnutAdtorNmNdAdtOpinionElement>,
+});
+
+impl AccnutAdtorNmNdAdtOpinion {
+    /// Rows with a modified opinion (한정, 부적정 or 의견거절).
+    pub fn modified_opinions(&self) -> impl Iterator<Item = &AccnutAdtorNmNdAdtOpinionElement> {
+        self.list.iter().filter(|element| {
+            element
+                .adt_opinion
+                .as_ref()
+                .is_some_and(AuditOpinion::is_modified)
+        })
+    }
+}
+
+derive_common! {
+    AccnutAdtorNmNdAdtOpinionElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 사업연도 (e.g. 제55기(당기))
+        pub bsns_year: String,
+        /// 감사인
+        pub adtor: String,
+        /// 감사의견
+        #[serde(default, deserialize_with = "optional_text")]
+        pub adt_opinion: Option<AuditOpinion>,
+        /// 감사보고서 특기사항
+        #[serde(default)]
+        pub adt_reprt_spcmnt_matter: Option<String>,
+        /// 강조사항 등
+        #[serde(default)]
+        pub emphs_matter: Option<String>,
+        /// 핵심감사사항
+        #[serde(default)]
+        pub core_adt_matter: Option<String>,
+        /// 결산기준일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub stlm_dt: Reported<NaiveDate>,
+    }
+}
+
+/// 감사의견
+///
+/// Known opinions are normalized (e.g. `적정의견` and `적정`),
+/// and anything else is kept as [`AuditOpinion::Other`].
+#[derive(
+    std::fmt::Debug,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    // serde
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[serde(from = "String", into = "String")]
+pub enum AuditOpinion {
+    /// 적정
+    Unqualified,
+    /// 한정
+    Qualified,
+    /// 부적정
+    Adverse,
+    /// 의견거절
+    Disclaimer,
+    Other(String),
+}
+
+impl AuditOpinion {
+    /// Whether the opinion is modified, i.e. anything but 적정.
+    ///
+    /// [`AuditOpinion::Other`] is not considered modified, since it can't be classified.
+    pub fn is_modified(&self) -> bool {
+        matches!(
+            self,
+            AuditOpinion::Qualified | AuditOpinion::Adverse | AuditOpinion::Disclaimer
+        )
+    }
+}
+
+impl From<String> for AuditOpinion {
+    fn from(value: String) -> Self {
+        let normalized = value
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>();
+        match normalized.trim_end_matches("의견") {
+            "적정" => AuditOpinion::Unqualified,
+            "한정" => AuditOpinion::Qualified,
+            "부적정" => AuditOpinion::Adverse,
+            "의견거절" | "거절" => AuditOpinion::Disclaimer,
+            _ => AuditOpinion::Other(value),
+        }
+    }
+}
+
+impl From<AuditOpinion> for String {
+    fn from(value: AuditOpinion) -> Self {
+        value.to_string()
+    }
+}
+
+impl fmt::Display for AuditOpinion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuditOpinion::Unqualified => f.write_str("적정"),
+            AuditOpinion::Qualified => f.write_str("한정"),
+            AuditOpinion::Adverse => f.write_str("부적정"),
+            AuditOpinion::Disclaimer => f.write_str("의견거절"),
+            AuditOpinion::Other(value) => f.write_str(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn audit_opinion_should_normalize_known_spellings() {
+        let cases = [
+            ("적정", AuditOpinion::Unqualified),
+            ("적정의견", AuditOpinion::Unqualified),
+            ("한정 의견", AuditOpinion::Qualified),
+            ("부적정", AuditOpinion::Adverse),
+            ("의견거절", AuditOpinion::Disclaimer),
+            (
+                "해당사항 없음",
+                AuditOpinion::Other("해당사항 없음".to_string()),
+            ),
+        ];
+
+        for (value, expected) in cases {
+            assert_eq!(
+                AuditOpinion::from(value.to_string()),
+                expected,
+                "value: {value}"
+            );
+        }
+        assert!(AuditOpinion::Disclaimer.is_modified());
+        assert!(!AuditOpinion::Unqualified.is_modified());
+    }
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bsns_year(mock::bsns_year())
+            .reprt_code(mock::reprt_code())
+            .build()
+            .expect("Failed to build AccnutAdtorNmNdAdtOpinionRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bsns_year, mock::bsns_year());
+        assert_eq!(params.reprt_code, mock::reprt_code());
+    }
+}
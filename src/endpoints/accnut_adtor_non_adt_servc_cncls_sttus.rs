@@ -0,0 +1,86 @@
+//! # 회계감사인과의 비감사용역 계약체결 현황
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS002&apiId=2020011>
+//!
+//! 정기보고서(사업, 분기, 반기보고서) 내에 회계감사인과의 비감사용역 계약체결 현황 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::{CorpCls, ReprtCode};
+use crate::endpoints::de::{money, optional_date};
+use crate::endpoints::identifiers::{BsnsYear, CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::money::Money;
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use chrono::NaiveDate;
+
+impl OpenDartApi {
+    pub async fn get_accnut_adtor_non_adt_servc_cncls_sttus(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<AccnutAdtorNonAdtServcCnclsSttus>>, OpenDartError>
+    {
+        self.get(self.url("/api/accnutAdtorNonAdtServcCnclsSttus.json"), args)
+            .await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    pub bsns_year: BsnsYear,
+    pub reprt_code: ReprtCode,
+);
+
+json_body!(AccnutAdtorNonAdtServcCnclsSttus {
+    pub list: Vec<AccnutAdtorNonAdtServcCnclsSttusElement>,
+});
+
+derive_common! {
+    AccnutAdtorNonAdtServcCnclsSttusElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 사업연도
+        pub bsns_year: String,
+        /// 계약체결일
+        pub cntrct_cncls_de: String,
+        /// 용역내용
+        pub servc_cn: String,
+        /// 용역수행기간
+        pub servc_exc_pd: String,
+        /// 용역보수
+        #[serde(default, deserialize_with = "money")]
+        pub servc_mendng: Reported<Money>,
+        /// 비고
+        pub rm: String,
+        /// 결산기준일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub stlm_dt: Reported<NaiveDate>,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bsns_year(mock::bsns_year())
+            .reprt_code(mock::reprt_code())
+            .build()
+            .expect("Failed to build AccnutAdtorNonAdtServcCnclsSttusRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bsns_year, mock::bsns_year());
+        assert_eq!(params.reprt_code, mock::reprt_code());
+    }
+}
@@ -0,0 +1,93 @@
+//! # 감사용역체결현황
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS002&apiId=2020010>
+//!
+//! 정기보고서(사업, 분기, 반기보고서) 내에 감사용역체결현황 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::{CorpCls, ReprtCode};
+use crate::endpoints::de::{money, optional_date};
+use crate::endpoints::identifiers::{BsnsYear, CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::money::Money;
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use chrono::NaiveDate;
+
+impl OpenDartApi {
+    pub async fn get_adt_servc_cncls_sttus(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<AdtServcCnclsSttus>>, OpenDartError> {
+        self.get(self.url("/api/adtServcCnclsSttus.json"), args)
+            .await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    pub bsns_year: BsnsYear,
+    pub reprt_code: ReprtCode,
+);
+
+json_body!(AdtServcCnclsSttus {
+    pub list: Vec<AdtServcCnclsSttusElement>,
+});
+
+derive_common! {
+    AdtServcCnclsSttusElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 사업연도
+        pub bsns_year: String,
+        /// 감사인
+        pub adtor: String,
+        /// 내용
+        pub cn: String,
+        /// 보수
+        #[serde(default, deserialize_with = "money")]
+        pub mendng: Reported<Money>,
+        /// 총소요시간
+        pub tot_reqre_time: String,
+        /// 감사계약내역(보수)
+        #[serde(default, deserialize_with = "money")]
+        pub adt_cntrct_dtls_mendng: Reported<Money>,
+        /// 감사계약내역(시간)
+        pub adt_cntrct_dtls_time: String,
+        /// 실제수행내역(보수)
+        #[serde(default, deserialize_with = "money")]
+        pub real_exc_dtls_mendng: Reported<Money>,
+        /// 실제수행내역(시간)
+        pub real_exc_dtls_time: String,
+        /// 결산기준일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub stlm_dt: Reported<NaiveDate>,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bsns_year(mock::bsns_year())
+            .reprt_code(mock::reprt_code())
+            .build()
+            .expect("Failed to build AdtServcCnclsSttusRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bsns_year, mock::bsns_year());
+        assert_eq!(params.reprt_code, mock::reprt_code());
+    }
+}
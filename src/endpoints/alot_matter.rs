@@ -0,0 +1,290 @@
+//! # 배당에 관한 사항
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS002&apiId=2019005>
+//!
+//! 정기보고서(사업, 분기, 반기보고서) 내에 배당에 관한 사항을 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::{CorpCls, ReprtCode};
+use crate::endpoints::de::{decimal, optional_date};
+use crate::endpoints::identifiers::{BsnsYear, CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::share_class::ShareClass;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use std::collections::BTreeMap;
+
+impl OpenDartApi {
+    pub async fn get_alot_matter(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<AlotMatter>>, OpenDartError> {
+        self.get(self.url("/api/alotMatter.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    pub bsns_year: BsnsYear,
+    pub reprt_code: ReprtCode,
+);
+
+json_body!(AlotMatter {
+    pub list: Vec<AlotMatterElement>,
+});
+
+impl AlotMatter {
+    /// Values of every row keyed by business year,
+    /// where `bsns_year` is the business year the report was requested for.
+    pub fn series(&self, bsns_year: i32) -> BTreeMap<DividendKey, BTreeMap<i32, Decimal>> {
+        let mut series: BTreeMap<DividendKey, BTreeMap<i32, Decimal>> = BTreeMap::new();
+        for element in &self.list {
+            let values = series.entry(element.key()).or_default();
+            for value in element.values(bsns_year) {
+                if let Some(amount) = value.value {
+                    values.insert(value.bsns_year, amount);
+                }
+            }
+        }
+        series
+    }
+}
+
+derive_common! {
+    AlotMatterElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 법인명
+        pub corp_name: String,
+        /// 구분 (e.g. 주당 현금배당금(원), 현금배당성향(%), 현금배당수익률(%))
+        pub se: String,
+        /// 주식 종류 (e.g. 보통주, 우선주)
+        #[serde(default)]
+        pub stock_knd: Option<ShareClass>,
+        /// 당기
+        #[serde(default, deserialize_with = "decimal")]
+        pub thstrm: Reported<Decimal>,
+        /// 전기
+        #[serde(default, deserialize_with = "decimal")]
+        pub frmtrm: Reported<Decimal>,
+        /// 전전기
+        #[serde(default, deserialize_with = "decimal")]
+        pub lwfr: Reported<Decimal>,
+        /// 결산기준일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub stlm_dt: Reported<NaiveDate>,
+    }
+}
+
+impl AlotMatterElement {
+    pub fn key(&self) -> DividendKey {
+        DividendKey {
+            se: self.se.clone(),
+            stock_knd: self.stock_knd.clone(),
+        }
+    }
+
+    pub fn value(&self, term: Term) -> Option<Decimal> {
+        match term {
+            Term::Current => self.thstrm.value(),
+            Term::Previous => self.frmtrm.value(),
+            Term::BeforePrevious => self.lwfr.value(),
+        }
+    }
+
+    /// Values of the three periods, oldest first,
+    /// where `bsns_year` is the business year the report was requested for.
+    pub fn values(&self, bsns_year: i32) -> [DividendValue; 3] {
+        [Term::BeforePrevious, Term::Previous, Term::Current].map(|term| DividendValue {
+            bsns_year: term.bsns_year(bsns_year),
+            term,
+            value: self.value(term),
+        })
+    }
+}
+
+derive_common! {
+    /// Identifies a row across reports of different business years.
+    DividendKey {
+        pub se: String,
+        pub stock_knd: Option<ShareClass>,
+    }
+}
+
+derive_common! {
+    DividendValue {
+        pub bsns_year: i32,
+        pub term: Term,
+        pub value: Option<Decimal>,
+    }
+}
+
+/// Period of a column relative to the business year of the report.
+#[derive(
+    std::fmt::Debug,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    // derive_more
+    derive_more::Display,
+    // serde
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub enum Term {
+    /// 당기 (`thstrm`)
+    #[display("thstrm")]
+    #[serde(rename = "thstrm")]
+    Current,
+    /// 전기 (`frmtrm`)
+    #[display("frmtrm")]
+    #[serde(rename = "frmtrm")]
+    Previous,
+    /// 전전기 (`lwfr`)
+    #[display("lwfr")]
+    #[serde(rename = "lwfr")]
+    BeforePrevious,
+}
+
+impl Term {
+    /// Business year of the period in a report for `bsns_year`.
+    pub fn bsns_year(self, bsns_year: i32) -> i32 {
+        match self {
+            Term::Current => bsns_year,
+            Term::Previous => bsns_year - 1,
+            Term::BeforePrevious => bsns_year - 2,
+        }
+    }
+}
+
+/// Multi-year dividend history built from the reports of successive business years.
+///
+/// When reports overlap, the value from the most recent report is kept,
+/// since figures of earlier periods may have been restated.
+#[derive(std::fmt::Debug, Clone, Default, Eq, PartialEq)]
+pub struct DividendHistory {
+    /// (business year of the value) -> (business year of the report, value)
+    series: BTreeMap<DividendKey, BTreeMap<i32, (i32, Decimal)>>,
+}
+
+impl DividendHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the rows of a report requested for `bsns_year`.
+    pub fn extend(&mut self, bsns_year: i32, alot_matter: &AlotMatter) {
+        for (key, values) in alot_matter.series(bsns_year) {
+            let series = self.series.entry(key).or_default();
+            for (year, value) in values {
+                match series.get(&year) {
+                    Some((reported_in, _)) if *reported_in > bsns_year => {}
+                    _ => {
+                        series.insert(year, (bsns_year, value));
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &DividendKey> {
+        self.series.keys()
+    }
+
+    /// Values of a row, keyed by business year in ascending order.
+    pub fn get(&self, se: &str, stock_knd: Option<ShareClass>) -> BTreeMap<i32, Decimal> {
+        let key = DividendKey {
+            se: se.to_string(),
+            stock_knd,
+        };
+        self.series
+            .get(&key)
+            .map(|values| {
+                values
+                    .iter()
+                    .map(|(year, (_, value))| (*year, *value))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.series.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.series.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    fn alot_matter(thstrm: i64, frmtrm: i64, lwfr: i64) -> AlotMatter {
+        AlotMatter {
+            status: "000".to_string(),
+            message: "정상".to_string(),
+            list: vec![AlotMatterElement {
+                rcept_no: mock::rcept_no(),
+                corp_cls: mock::corp_cls(),
+                corp_code: mock::corp_code(),
+                corp_name: mock::corp_name(),
+                se: "주당 현금배당금(원)".to_string(),
+                stock_knd: Some(ShareClass::Common),
+                thstrm: Reported::Parsed(thstrm.into()),
+                frmtrm: Reported::Parsed(frmtrm.into()),
+                lwfr: Reported::Parsed(lwfr.into()),
+                stlm_dt: Reported::Empty,
+            }],
+        }
+    }
+
+    #[test]
+    fn dividend_history_should_prefer_recent_reports() {
+        let mut history = DividendHistory::new();
+        history.extend(2023, &alot_matter(1_444, 1_444, 1_450));
+        history.extend(2021, &alot_matter(1_440, 2_994, 1_416));
+        history.extend(2022, &alot_matter(1_444, 1_445, 2_994));
+
+        let dps = history.get("주당 현금배당금(원)", Some(ShareClass::Common));
+        let expected = [
+            (2019, 1_416),
+            (2020, 2_994),
+            (2021, 1_450),
+            (2022, 1_444),
+            (2023, 1_444),
+        ]
+        .into_iter()
+        .map(|(year, value)| (year, Decimal::from(value)))
+        .collect::<BTreeMap<_, _>>();
+        assert_eq!(dps, expected);
+        assert_eq!(history.len(), 1);
+    }
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bsns_year(mock::bsns_year())
+            .reprt_code(mock::reprt_code())
+            .build()
+            .expect("Failed to build AlotMatterRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bsns_year, mock::bsns_year());
+        assert_eq!(params.reprt_code, mock::reprt_code());
+    }
+}
@@ -0,0 +1,89 @@
+//! # 자산양수도(기타), 풋백옵션
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS005&apiId=2020038>
+//!
+//! 주요사항보고서 내에 자산양수도(기타), 풋백옵션 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::CorpCls;
+use crate::endpoints::de::krw;
+use crate::endpoints::identifiers::{CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use crate::validate::fields::yyyymmdd;
+
+impl OpenDartApi {
+    pub async fn get_ast_inhtrf_etc_ptbk_opt(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<AstInhtrfEtcPtbkOpt>>, OpenDartError> {
+        self.get(self.url("/api/astInhtrfEtcPtbkOpt.json"), args)
+            .await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub bgn_de: String,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub end_de: String,
+);
+
+json_body!(AstInhtrfEtcPtbkOpt {
+    pub list: Vec<AstInhtrfEtcPtbkOptElement>,
+});
+
+derive_common! {
+    AstInhtrfEtcPtbkOptElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 보고 사유
+        pub rp_rsn: String,
+        /// 자산양수ㆍ도 가액
+        #[serde(default, deserialize_with = "krw")]
+        pub ast_inhtrf_prc: Reported<i64>,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de(mock::yyyymmdd())
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build AstInhtrfEtcPtbkOptRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bgn_de, mock::yyyymmdd());
+        assert_eq!(params.end_de, "20231231");
+    }
+
+    #[tokio::test]
+    async fn invalid_params_should_fail_before_making_request() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de("invalid")
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build AstInhtrfEtcPtbkOptRequestParams");
+
+        let api = OpenDartApi::default();
+        let response = api.get_ast_inhtrf_etc_ptbk_opt(params).await;
+        assert!(response.is_err());
+    }
+}
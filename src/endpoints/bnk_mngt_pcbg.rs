@@ -0,0 +1,96 @@
+//! # 채권은행 등의 관리절차 개시
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS005&apiId=2020027>
+//!
+//! 주요사항보고서 내에 채권은행 등의 관리절차 개시 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::CorpCls;
+use crate::endpoints::de::optional_date;
+use crate::endpoints::identifiers::{CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use crate::validate::fields::yyyymmdd;
+use chrono::NaiveDate;
+
+impl OpenDartApi {
+    pub async fn get_bnk_mngt_pcbg(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<BnkMngtPcbg>>, OpenDartError> {
+        self.get(self.url("/api/bnkMngtPcbg.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub bgn_de: String,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub end_de: String,
+);
+
+json_body!(BnkMngtPcbg {
+    pub list: Vec<BnkMngtPcbgElement>,
+});
+
+derive_common! {
+    BnkMngtPcbgElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 관리절차개시 결정일자
+        #[serde(default, deserialize_with = "optional_date")]
+        pub mngt_pcbg_dd: Reported<NaiveDate>,
+        /// 관리기관
+        pub mngt_int: String,
+        /// 관리기간
+        pub mngt_pd: String,
+        /// 관리사유
+        pub mngt_rs: String,
+        /// 확인일자
+        #[serde(default, deserialize_with = "optional_date")]
+        pub cfd: Reported<NaiveDate>,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de(mock::yyyymmdd())
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build BnkMngtPcbgRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bgn_de, mock::yyyymmdd());
+        assert_eq!(params.end_de, "20231231");
+    }
+
+    #[tokio::test]
+    async fn invalid_params_should_fail_before_making_request() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de("invalid")
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build BnkMngtPcbgRequestParams");
+
+        let api = OpenDartApi::default();
+        let response = api.get_bnk_mngt_pcbg(params).await;
+        assert!(response.is_err());
+    }
+}
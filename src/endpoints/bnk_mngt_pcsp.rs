@@ -0,0 +1,96 @@
+//! # 채권은행 등의 관리절차 중단
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS005&apiId=2020036>
+//!
+//! 주요사항보고서 내에 채권은행 등의 관리절차 중단 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::CorpCls;
+use crate::endpoints::de::optional_date;
+use crate::endpoints::identifiers::{CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use crate::validate::fields::yyyymmdd;
+use chrono::NaiveDate;
+
+impl OpenDartApi {
+    pub async fn get_bnk_mngt_pcsp(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<BnkMngtPcsp>>, OpenDartError> {
+        self.get(self.url("/api/bnkMngtPcsp.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub bgn_de: String,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub end_de: String,
+);
+
+json_body!(BnkMngtPcsp {
+    pub list: Vec<BnkMngtPcspElement>,
+});
+
+derive_common! {
+    BnkMngtPcspElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 관리절차중단 결정일자
+        #[serde(default, deserialize_with = "optional_date")]
+        pub mngt_pcsp_dd: Reported<NaiveDate>,
+        /// 관리기관
+        pub mngt_int: String,
+        /// 중단사유
+        pub sp_rs: String,
+        /// 향후대책
+        pub ft_ctp: String,
+        /// 확인일자
+        #[serde(default, deserialize_with = "optional_date")]
+        pub cfd: Reported<NaiveDate>,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de(mock::yyyymmdd())
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build BnkMngtPcspRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bgn_de, mock::yyyymmdd());
+        assert_eq!(params.end_de, "20231231");
+    }
+
+    #[tokio::test]
+    async fn invalid_params_should_fail_before_making_request() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de("invalid")
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build BnkMngtPcspRequestParams");
+
+        let api = OpenDartApi::default();
+        let response = api.get_bnk_mngt_pcsp(params).await;
+        assert!(response.is_err());
+    }
+}
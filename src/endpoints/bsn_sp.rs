@@ -0,0 +1,122 @@
+//! # 영업정지
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS005&apiId=2020020>
+//!
+//! 주요사항보고서 내에 영업정지 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::CorpCls;
+use crate::endpoints::de::{amount, decimal, krw, optional_date};
+use crate::endpoints::identifiers::{CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use crate::validate::fields::yyyymmdd;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+impl OpenDartApi {
+    pub async fn get_bsn_sp(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<BsnSp>>, OpenDartError> {
+        self.get(self.url("/api/bsnSp.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub bgn_de: String,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub end_de: String,
+);
+
+json_body!(BsnSp {
+    pub list: Vec<BsnSpElement>,
+});
+
+derive_common! {
+    BsnSpElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 영업정지 분야
+        pub bsnsp_rm: String,
+        /// 영업정지 내역(영업정지금액)
+        #[serde(default, deserialize_with = "krw")]
+        pub bsnsp_amt: Reported<i64>,
+        /// 영업정지 내역(최근매출총액)
+        #[serde(default, deserialize_with = "krw")]
+        pub rsl: Reported<i64>,
+        /// 영업정지 내역(매출액 대비)
+        #[serde(default, deserialize_with = "decimal")]
+        pub sl_vs: Reported<Decimal>,
+        /// 영업정지 내역(대규모법인여부)
+        pub ls_atn: String,
+        /// 영업정지 내역(거래소 의무공시 해당 여부)
+        pub krx_stt_atn: String,
+        /// 영업정지 내용
+        pub bsnsp_cn: String,
+        /// 영업정지사유
+        pub bsnsp_rs: String,
+        /// 향후대책
+        pub ft_ctp: String,
+        /// 영업정지영향
+        pub bsnsp_af: String,
+        /// 영업정지일자
+        #[serde(default, deserialize_with = "optional_date")]
+        pub bsnspd: Reported<NaiveDate>,
+        /// 이사회결의일(결정일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub bddd: Reported<NaiveDate>,
+        /// 사외이사 참석여부(참석)
+        #[serde(default, deserialize_with = "amount")]
+        pub od_a_at_t: Reported<i64>,
+        /// 사외이사 참석여부(불참)
+        #[serde(default, deserialize_with = "amount")]
+        pub od_a_at_b: Reported<i64>,
+        /// 감사(사외이사가 아닌 감사위원) 참석여부
+        pub adt_a_atn: String,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de(mock::yyyymmdd())
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build BsnSpRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bgn_de, mock::yyyymmdd());
+        assert_eq!(params.end_de, "20231231");
+    }
+
+    #[tokio::test]
+    async fn invalid_params_should_fail_before_making_request() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de("invalid")
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build BsnSpRequestParams");
+
+        let api = OpenDartApi::default();
+        let response = api.get_bsn_sp(params).await;
+        assert!(response.is_err());
+    }
+}
@@ -0,0 +1,211 @@
+//! # 회사분할 결정
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS005&apiId=2020052>
+//!
+//! 주요사항보고서 내에 회사분할 결정 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::CorpCls;
+use crate::endpoints::de::{amount, decimal, krw, optional_date};
+use crate::endpoints::identifiers::{CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::restructuring::ExchangeRatio;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use crate::validate::fields::yyyymmdd;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+impl OpenDartApi {
+    pub async fn get_cmp_dv_decsn(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<CmpDvDecsn>>, OpenDartError> {
+        self.get(self.url("/api/cmpDvDecsn.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub bgn_de: String,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub end_de: String,
+);
+
+json_body!(CmpDvDecsn {
+    pub list: Vec<CmpDvDecsnElement>,
+});
+
+derive_common! {
+    CmpDvDecsnElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 분할방법
+        pub dv_mth: String,
+        /// 분할의 중요영향 및 효과
+        pub dv_impef: String,
+        /// 분할비율
+        pub dv_rt: String,
+        /// 분할로 이전할 사업 및 재산의 내용
+        pub dv_trfbsn: String,
+        /// 분할 후 존속회사(회사명)
+        pub atdv_excmp_cmpnm: String,
+        /// 분할 후 존속회사(분할후 재무내용(자산총계 (원)))
+        #[serde(default, deserialize_with = "krw")]
+        pub atdvfdtl_tast: Reported<i64>,
+        /// 분할 후 존속회사(분할후 재무내용(부채총계 (원)))
+        #[serde(default, deserialize_with = "krw")]
+        pub atdvfdtl_tdbt: Reported<i64>,
+        /// 분할 후 존속회사(분할후 재무내용(자본총계 (원)))
+        #[serde(default, deserialize_with = "krw")]
+        pub atdvfdtl_teqt: Reported<i64>,
+        /// 분할 후 존속회사(분할후 재무내용(자본금 (원)))
+        #[serde(default, deserialize_with = "krw")]
+        pub atdvfdtl_cpt: Reported<i64>,
+        /// 분할 후 존속회사(분할후 재무내용(현재기준))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub atdvfdtl_std: Reported<NaiveDate>,
+        /// 분할 후 존속회사(존속사업부문 최근 사업연도매출액 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub atdv_excmpbsn_rsl: Reported<i64>,
+        /// 분할 후 존속회사(주요사업)
+        pub atdv_excmp_mbsn: String,
+        /// 분할 후 존속회사(분할 후 상장유지 여부)
+        pub atdv_excmp_atdv_lstmn_atn: String,
+        /// 분할설립회사(회사명)
+        pub dvfcmp_cmpnm: String,
+        /// 분할설립회사(설립시 재무내용(자산총계 (원)))
+        #[serde(default, deserialize_with = "krw")]
+        pub ffdtl_tast: Reported<i64>,
+        /// 분할설립회사(설립시 재무내용(부채총계 (원)))
+        #[serde(default, deserialize_with = "krw")]
+        pub ffdtl_tdbt: Reported<i64>,
+        /// 분할설립회사(설립시 재무내용(자본총계 (원)))
+        #[serde(default, deserialize_with = "krw")]
+        pub ffdtl_teqt: Reported<i64>,
+        /// 분할설립회사(설립시 재무내용(자본금 (원)))
+        #[serde(default, deserialize_with = "krw")]
+        pub ffdtl_cpt: Reported<i64>,
+        /// 분할설립회사(설립시 재무내용(현재기준))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub ffdtl_std: Reported<NaiveDate>,
+        /// 분할설립회사(신설사업부문 최근 사업연도 매출액 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub dvfcmpbsn_rsl: Reported<i64>,
+        /// 분할설립회사(주요사업)
+        pub dvfcmp_mbsn: String,
+        /// 분할설립회사(재상장신청 여부)
+        pub dvfcmp_rlst_atn: String,
+        /// 감자에 관한 사항(감자비율 (%))
+        #[serde(default, deserialize_with = "decimal")]
+        pub abcr_crrt: Reported<Decimal>,
+        /// 감자에 관한 사항(구주권 제출기간(시작일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub abcr_osprpd_bgd: Reported<NaiveDate>,
+        /// 감자에 관한 사항(구주권 제출기간(종료일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub abcr_osprpd_edd: Reported<NaiveDate>,
+        /// 감자에 관한 사항(매매거래정지 예정기간(시작일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub abcr_trspprpd_bgd: Reported<NaiveDate>,
+        /// 감자에 관한 사항(매매거래정지 예정기간(종료일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub abcr_trspprpd_edd: Reported<NaiveDate>,
+        /// 감자에 관한 사항(신주배정조건)
+        pub abcr_nstkascnd: String,
+        /// 감자에 관한 사항(주주 주식수 비례여부 및 사유)
+        pub abcr_shstkcnt_rt_at_rs: String,
+        /// 감자에 관한 사항(신주배정기준일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub abcr_nstkasstd: Reported<NaiveDate>,
+        /// 감자에 관한 사항(신주권교부예정일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub abcr_nstkdlprd: Reported<NaiveDate>,
+        /// 감자에 관한 사항(신주의 상장예정일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub abcr_nstklstprd: Reported<NaiveDate>,
+        /// 주주총회 예정일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub gmtsck_prd: Reported<NaiveDate>,
+        /// 채권자 이의제출기간(시작일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub cdobprpd_bgd: Reported<NaiveDate>,
+        /// 채권자 이의제출기간(종료일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub cdobprpd_edd: Reported<NaiveDate>,
+        /// 분할기일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub dvdt: Reported<NaiveDate>,
+        /// 분할등기 예정일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub dvrgsprd: Reported<NaiveDate>,
+        /// 이사회결의일(결정일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub bddd: Reported<NaiveDate>,
+        /// 사외이사 참석여부(참석)
+        #[serde(default, deserialize_with = "amount")]
+        pub od_a_at_t: Reported<i64>,
+        /// 사외이사 참석여부(불참)
+        #[serde(default, deserialize_with = "amount")]
+        pub od_a_at_b: Reported<i64>,
+        /// 감사(사외이사가 아닌 감사위원) 참석여부
+        pub adt_a_atn: String,
+        /// 풋옵션 등 계약 체결여부
+        pub popt_ctr_atn: String,
+        /// 계약내용
+        pub popt_ctr_cn: String,
+        /// 증권신고서 제출대상 여부
+        pub rs_sm_atn: String,
+        /// 제출을 면제받은 경우 그 사유
+        pub ex_sm_r: String,
+    }
+}
+
+impl CmpDvDecsnElement {
+    /// 분할비율, parsed from `dv_rt`
+    pub fn split_ratio(&self) -> Option<ExchangeRatio> {
+        self.dv_rt.parse().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de(mock::yyyymmdd())
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build CmpDvDecsnRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bgn_de, mock::yyyymmdd());
+        assert_eq!(params.end_de, "20231231");
+    }
+
+    #[tokio::test]
+    async fn invalid_params_should_fail_before_making_request() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de("invalid")
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build CmpDvDecsnRequestParams");
+
+        let api = OpenDartApi::default();
+        let response = api.get_cmp_dv_decsn(params).await;
+        assert!(response.is_err());
+    }
+}
@@ -0,0 +1,311 @@
+//! # 회사분할합병 결정
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS005&apiId=2020053>
+//!
+//! 주요사항보고서 내에 회사분할합병 결정 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::CorpCls;
+use crate::endpoints::de::{amount, decimal, krw, optional_date};
+use crate::endpoints::identifiers::{CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::restructuring::{ExchangeRatio, ExternalValuation};
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use crate::validate::fields::yyyymmdd;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+impl OpenDartApi {
+    pub async fn get_cmp_dvmg_decsn(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<CmpDvmgDecsn>>, OpenDartError> {
+        self.get(self.url("/api/cmpDvmgDecsn.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub bgn_de: String,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub end_de: String,
+);
+
+json_body!(CmpDvmgDecsn {
+    pub list: Vec<CmpDvmgDecsnElement>,
+});
+
+derive_common! {
+    CmpDvmgDecsnElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 분할합병 방법
+        pub dvmg_mth: String,
+        /// 분할합병의 중요영향 및 효과
+        pub dvmg_impef: String,
+        /// 분할로 이전할 사업 및 재산의 내용
+        pub dv_trfbsn: String,
+        /// 분할 후 존속회사(회사명)
+        pub atdv_excmp_cmpnm: String,
+        /// 분할 후 존속회사(분할후 재무내용(자산총계 (원)))
+        #[serde(default, deserialize_with = "krw")]
+        pub atdvfdtl_tast: Reported<i64>,
+        /// 분할 후 존속회사(분할후 재무내용(부채총계 (원)))
+        #[serde(default, deserialize_with = "krw")]
+        pub atdvfdtl_tdbt: Reported<i64>,
+        /// 분할 후 존속회사(분할후 재무내용(자본총계 (원)))
+        #[serde(default, deserialize_with = "krw")]
+        pub atdvfdtl_teqt: Reported<i64>,
+        /// 분할 후 존속회사(분할후 재무내용(자본금 (원)))
+        #[serde(default, deserialize_with = "krw")]
+        pub atdvfdtl_cpt: Reported<i64>,
+        /// 분할 후 존속회사(분할후 재무내용(현재기준))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub atdvfdtl_std: Reported<NaiveDate>,
+        /// 분할 후 존속회사(존속사업부문 최근 사업연도매출액 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub atdv_excmpbsn_rsl: Reported<i64>,
+        /// 분할 후 존속회사(주요사업)
+        pub atdv_excmp_mbsn: String,
+        /// 분할 후 존속회사(분할 후 상장유지 여부)
+        pub atdv_excmp_atdv_lstmn_atn: String,
+        /// 분할설립 회사(회사명)
+        pub dvfcmp_cmpnm: String,
+        /// 분할설립 회사(설립시 재무내용(자산총계 (원)))
+        #[serde(default, deserialize_with = "krw")]
+        pub ffdtl_tast: Reported<i64>,
+        /// 분할설립 회사(설립시 재무내용(부채총계 (원)))
+        #[serde(default, deserialize_with = "krw")]
+        pub ffdtl_tdbt: Reported<i64>,
+        /// 분할설립 회사(설립시 재무내용(자본총계 (원)))
+        #[serde(default, deserialize_with = "krw")]
+        pub ffdtl_teqt: Reported<i64>,
+        /// 분할설립 회사(설립시 재무내용(자본금 (원)))
+        #[serde(default, deserialize_with = "krw")]
+        pub ffdtl_cpt: Reported<i64>,
+        /// 분할설립 회사(설립시 재무내용(현재기준))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub ffdtl_std: Reported<NaiveDate>,
+        /// 분할설립 회사(신설사업부문 최근 사업연도 매출액 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub dvfcmp_nbsn_rsl: Reported<i64>,
+        /// 분할설립 회사(주요사업)
+        pub dvfcmp_mbsn: String,
+        /// 분할설립 회사(분할후 상장유지 여부)
+        pub dvfcmp_rlst_atn: String,
+        /// 감자에 관한 사항(감자비율 (%))
+        #[serde(default, deserialize_with = "decimal")]
+        pub abcr_crrt: Reported<Decimal>,
+        /// 감자에 관한 사항(구주권 제출기간(시작일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub abcr_osprpd_bgd: Reported<NaiveDate>,
+        /// 감자에 관한 사항(구주권 제출기간(종료일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub abcr_osprpd_edd: Reported<NaiveDate>,
+        /// 감자에 관한 사항(매매거래정지 예정기간(시작일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub abcr_trspprpd_bgd: Reported<NaiveDate>,
+        /// 감자에 관한 사항(매매거래정지 예정기간(종료일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub abcr_trspprpd_edd: Reported<NaiveDate>,
+        /// 감자에 관한 사항(신주배정조건)
+        pub abcr_nstkascnd: String,
+        /// 감자에 관한 사항(주주 주식수 비례여부 및 사유)
+        pub abcr_shstkcnt_rt_at_rs: String,
+        /// 감자에 관한 사항(신주배정기준일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub abcr_nstkasstd: Reported<NaiveDate>,
+        /// 감자에 관한 사항(신주권교부예정일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub abcr_nstkdlprd: Reported<NaiveDate>,
+        /// 감자에 관한 사항(신주의 상장예정일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub abcr_nstklstprd: Reported<NaiveDate>,
+        /// 분할합병비율
+        pub dvmg_rt: String,
+        /// 분할합병비율 산출근거
+        pub dvmg_rt_bs: String,
+        /// 외부평가에 관한 사항(외부평가 여부)
+        pub exevl_atn: String,
+        /// 외부평가에 관한 사항(근거 및 사유)
+        pub exevl_bs_rs: String,
+        /// 외부평가에 관한 사항(외부평가기관의 명칭)
+        pub exevl_intn: String,
+        /// 외부평가에 관한 사항(외부평가 기간)
+        pub exevl_pd: String,
+        /// 외부평가에 관한 사항(외부평가 의견)
+        pub exevl_op: String,
+        /// 분할합병신주의 종류와 수(주)(보통주식)
+        #[serde(default, deserialize_with = "amount")]
+        pub dvmgnstk_ostk_cnt: Reported<i64>,
+        /// 분할합병신주의 종류와 수(주)(종류주식)
+        #[serde(default, deserialize_with = "amount")]
+        pub dvmgnstk_cstk_cnt: Reported<i64>,
+        /// 분할합병 상대회사(회사명)
+        pub dvmgptncmp_cmpnm: String,
+        /// 분할합병 상대회사(주요사업)
+        pub dvmgptncmp_mbsn: String,
+        /// 분할합병 상대회사(회사와의 관계)
+        pub dvmgptncmp_rl_cmpn: String,
+        /// 상대회사 최근 사업연도 재무내용(자산총계 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub rbsnfdtl_tast: Reported<i64>,
+        /// 상대회사 최근 사업연도 재무내용(부채총계 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub rbsnfdtl_tdbt: Reported<i64>,
+        /// 상대회사 최근 사업연도 재무내용(자본총계 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub rbsnfdtl_teqt: Reported<i64>,
+        /// 상대회사 최근 사업연도 재무내용(자본금 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub rbsnfdtl_cpt: Reported<i64>,
+        /// 상대회사 최근 사업연도 재무내용(매출액 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub rbsnfdtl_sl: Reported<i64>,
+        /// 상대회사 최근 사업연도 재무내용(당기순이익 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub rbsnfdtl_nic: Reported<i64>,
+        /// 외부감사 여부(기관명)
+        pub eadtat_intn: String,
+        /// 외부감사 여부(감사의견)
+        pub eadtat_op: String,
+        /// 분할합병일정(분할합병계약일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub dvmgsc_dvmgctrd: Reported<NaiveDate>,
+        /// 분할합병일정(주주확정기준일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub dvmgsc_shddstd: Reported<NaiveDate>,
+        /// 분할합병일정(주주명부 폐쇄기간(시작일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub dvmgsc_shclspd_bgd: Reported<NaiveDate>,
+        /// 분할합병일정(주주명부 폐쇄기간(종료일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub dvmgsc_shclspd_edd: Reported<NaiveDate>,
+        /// 분할합병일정(분할합병반대의사통지 접수기간(시작일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub dvmgsc_dvmgop_rcpd_bgd: Reported<NaiveDate>,
+        /// 분할합병일정(분할합병반대의사통지 접수기간(종료일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub dvmgsc_dvmgop_rcpd_edd: Reported<NaiveDate>,
+        /// 분할합병일정(주주총회예정일자)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub dvmgsc_gmtsck_prd: Reported<NaiveDate>,
+        /// 분할합병일정(주식매수청구권 행사기간(시작일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub dvmgsc_aprskh_expd_bgd: Reported<NaiveDate>,
+        /// 분할합병일정(주식매수청구권 행사기간(종료일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub dvmgsc_aprskh_expd_edd: Reported<NaiveDate>,
+        /// 분할합병일정(채권자 이의 제출기간(시작일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub dvmgsc_cdobprpd_bgd: Reported<NaiveDate>,
+        /// 분할합병일정(채권자 이의 제출기간(종료일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub dvmgsc_cdobprpd_edd: Reported<NaiveDate>,
+        /// 분할합병일정(분할합병기일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub dvmgsc_dvmgdt: Reported<NaiveDate>,
+        /// 분할합병일정(종료보고 총회일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub dvmgsc_ergmd: Reported<NaiveDate>,
+        /// 분할합병일정(분할합병등기예정일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub dvmgsc_dvmgrgsprd: Reported<NaiveDate>,
+        /// 우회상장 해당 여부
+        pub bdlst_atn: String,
+        /// 타법인의 우회상장 요건 충족여부
+        pub otcpr_bdlst_sf_atn: String,
+        /// 주식매수청구권에 관한 사항(행사요건)
+        pub aprskh_exrq: String,
+        /// 주식매수청구권에 관한 사항(매수예정가격)
+        pub aprskh_plnprc: String,
+        /// 주식매수청구권에 관한 사항(행사절차, 방법, 기간, 장소)
+        pub aprskh_ex_pc_mth_pd_pl: String,
+        /// 주식매수청구권에 관한 사항(지급예정시기, 지급방법)
+        pub aprskh_pym_plpd_mth: String,
+        /// 주식매수청구권에 관한 사항(주식매수청구권 제한 관련 내용)
+        pub aprskh_lmt: String,
+        /// 주식매수청구권에 관한 사항(계약에 미치는 효력)
+        pub aprskh_ctref: String,
+        /// 이사회결의일(결정일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub bddd: Reported<NaiveDate>,
+        /// 사외이사 참석여부(참석)
+        #[serde(default, deserialize_with = "amount")]
+        pub od_a_at_t: Reported<i64>,
+        /// 사외이사 참석여부(불참)
+        #[serde(default, deserialize_with = "amount")]
+        pub od_a_at_b: Reported<i64>,
+        /// 감사(사외이사가 아닌 감사위원) 참석여부
+        pub adt_a_atn: String,
+        /// 풋옵션 등 계약 체결여부
+        pub popt_ctr_atn: String,
+        /// 계약내용
+        pub popt_ctr_cn: String,
+        /// 증권신고서 제출대상 여부
+        pub rs_sm_atn: String,
+        /// 제출을 면제받은 경우 그 사유
+        pub ex_sm_r: String,
+    }
+}
+
+impl CmpDvmgDecsnElement {
+    /// 분할합병비율, parsed from `dvmg_rt`
+    pub fn split_merger_ratio(&self) -> Option<ExchangeRatio> {
+        self.dvmg_rt.parse().ok()
+    }
+
+    /// 외부평가에 관한 사항
+    pub fn external_valuation(&self) -> ExternalValuation<'_> {
+        ExternalValuation::new(
+            &self.exevl_atn,
+            &self.exevl_bs_rs,
+            &self.exevl_intn,
+            &self.exevl_pd,
+            &self.exevl_op,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de(mock::yyyymmdd())
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build CmpDvmgDecsnRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bgn_de, mock::yyyymmdd());
+        assert_eq!(params.end_de, "20231231");
+    }
+
+    #[tokio::test]
+    async fn invalid_params_should_fail_before_making_request() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de("invalid")
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build CmpDvmgDecsnRequestParams");
+
+        let api = OpenDartApi::default();
+        let response = api.get_cmp_dvmg_decsn(params).await;
+        assert!(response.is_err());
+    }
+}
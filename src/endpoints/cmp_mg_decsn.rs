@@ -0,0 +1,272 @@
+//! # 회사합병 결정
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS005&apiId=2020051>
+//!
+//! 주요사항보고서 내에 회사합병 결정 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::CorpCls;
+use crate::endpoints::de::{amount, krw, optional_date};
+use crate::endpoints::identifiers::{CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::restructuring::{ExchangeRatio, ExternalValuation};
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use crate::validate::fields::yyyymmdd;
+use chrono::NaiveDate;
+
+impl OpenDartApi {
+    pub async fn get_cmp_mg_decsn(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<CmpMgDecsn>>, OpenDartError> {
+        self.get(self.url("/api/cmpMgDecsn.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub bgn_de: String,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub end_de: String,
+);
+
+json_body!(CmpMgDecsn {
+    pub list: Vec<CmpMgDecsnElement>,
+});
+
+derive_common! {
+    CmpMgDecsnElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 합병방법
+        pub mg_mth: String,
+        /// 합병형태
+        pub mg_stn: String,
+        /// 합병목적
+        pub mg_pp: String,
+        /// 합병비율
+        pub mg_rt: String,
+        /// 합병비율 산출근거
+        pub mg_rt_bs: String,
+        /// 외부평가에 관한 사항(외부평가 여부)
+        pub exevl_atn: String,
+        /// 외부평가에 관한 사항(근거 및 사유)
+        pub exevl_bs_rs: String,
+        /// 외부평가에 관한 사항(외부평가기관의 명칭)
+        pub exevl_intn: String,
+        /// 외부평가에 관한 사항(외부평가 기간)
+        pub exevl_pd: String,
+        /// 외부평가에 관한 사항(외부평가 의견)
+        pub exevl_op: String,
+        /// 합병신주의 종류와 수(주)(보통주식)
+        #[serde(default, deserialize_with = "amount")]
+        pub mgnstk_ostk_cnt: Reported<i64>,
+        /// 합병신주의 종류와 수(주)(종류주식)
+        #[serde(default, deserialize_with = "amount")]
+        pub mgnstk_cstk_cnt: Reported<i64>,
+        /// 합병상대회사(회사명)
+        pub mgptncmp_cmpnm: String,
+        /// 합병상대회사(주요사업)
+        pub mgptncmp_mbsn: String,
+        /// 합병상대회사(회사와의 관계)
+        pub mgptncmp_rl_cmpn: String,
+        /// 상대회사 최근 사업연도 재무내용(자산총계 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub rbsnfdtl_tast: Reported<i64>,
+        /// 상대회사 최근 사업연도 재무내용(부채총계 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub rbsnfdtl_tdbt: Reported<i64>,
+        /// 상대회사 최근 사업연도 재무내용(자본총계 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub rbsnfdtl_teqt: Reported<i64>,
+        /// 상대회사 최근 사업연도 재무내용(자본금 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub rbsnfdtl_cpt: Reported<i64>,
+        /// 상대회사 최근 사업연도 재무내용(매출액 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub rbsnfdtl_sl: Reported<i64>,
+        /// 상대회사 최근 사업연도 재무내용(당기순이익 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub rbsnfdtl_nic: Reported<i64>,
+        /// 외부감사 여부(기관명)
+        pub eadtat_intn: String,
+        /// 외부감사 여부(감사의견)
+        pub eadtat_op: String,
+        /// 신설합병회사(회사명)
+        pub nmgcmp_cmpnm: String,
+        /// 신설합병회사(설립시 재무내용(자산총계 (원)))
+        #[serde(default, deserialize_with = "krw")]
+        pub ffdtl_tast: Reported<i64>,
+        /// 신설합병회사(설립시 재무내용(부채총계 (원)))
+        #[serde(default, deserialize_with = "krw")]
+        pub ffdtl_tdbt: Reported<i64>,
+        /// 신설합병회사(설립시 재무내용(자본총계 (원)))
+        #[serde(default, deserialize_with = "krw")]
+        pub ffdtl_teqt: Reported<i64>,
+        /// 신설합병회사(설립시 재무내용(자본금 (원)))
+        #[serde(default, deserialize_with = "krw")]
+        pub ffdtl_cpt: Reported<i64>,
+        /// 신설합병회사(설립시 재무내용(현재기준))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub ffdtl_std: Reported<NaiveDate>,
+        /// 신설합병회사(신설사업부문 최근 사업연도 매출액 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub nmgcmp_nbsn_rsl: Reported<i64>,
+        /// 신설합병회사(주요사업)
+        pub nmgcmp_mbsn: String,
+        /// 신설합병회사(재상장신청 여부)
+        pub nmgcmp_rlst_atn: String,
+        /// 합병일정(합병계약일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub mgsc_mgctrd: Reported<NaiveDate>,
+        /// 합병일정(주주확정기준일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub mgsc_shddstd: Reported<NaiveDate>,
+        /// 합병일정(주주명부 폐쇄기간(시작일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub mgsc_shclspd_bgd: Reported<NaiveDate>,
+        /// 합병일정(주주명부 폐쇄기간(종료일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub mgsc_shclspd_edd: Reported<NaiveDate>,
+        /// 합병일정(합병반대의사통지 접수기간(시작일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub mgsc_mgop_rcpd_bgd: Reported<NaiveDate>,
+        /// 합병일정(합병반대의사통지 접수기간(종료일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub mgsc_mgop_rcpd_edd: Reported<NaiveDate>,
+        /// 합병일정(주주총회예정일자)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub mgsc_gmtsck_prd: Reported<NaiveDate>,
+        /// 합병일정(주식매수청구권 행사기간(시작일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub mgsc_aprskh_expd_bgd: Reported<NaiveDate>,
+        /// 합병일정(주식매수청구권 행사기간(종료일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub mgsc_aprskh_expd_edd: Reported<NaiveDate>,
+        /// 합병일정(구주권 제출기간(시작일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub mgsc_osprpd_bgd: Reported<NaiveDate>,
+        /// 합병일정(구주권 제출기간(종료일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub mgsc_osprpd_edd: Reported<NaiveDate>,
+        /// 합병일정(매매거래 정지예정기간(시작일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub mgsc_trspprpd_bgd: Reported<NaiveDate>,
+        /// 합병일정(매매거래 정지예정기간(종료일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub mgsc_trspprpd_edd: Reported<NaiveDate>,
+        /// 합병일정(채권자이의 제출기간(시작일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub mgsc_cdobprpd_bgd: Reported<NaiveDate>,
+        /// 합병일정(채권자이의 제출기간(종료일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub mgsc_cdobprpd_edd: Reported<NaiveDate>,
+        /// 합병일정(합병기일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub mgsc_mgdt: Reported<NaiveDate>,
+        /// 합병일정(종료보고 총회일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub mgsc_ergmd: Reported<NaiveDate>,
+        /// 합병일정(합병등기예정일자)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub mgsc_rgsprpd: Reported<NaiveDate>,
+        /// 합병일정(신주권교부예정일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub mgsc_nstkdlprd: Reported<NaiveDate>,
+        /// 합병일정(신주의 상장예정일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub mgsc_nstklstprd: Reported<NaiveDate>,
+        /// 우회상장 해당 여부
+        pub bdlst_atn: String,
+        /// 타법인의 우회상장 요건 충족여부
+        pub otcpr_bdlst_sf_atn: String,
+        /// 주식매수청구권에 관한 사항(매수예정가격)
+        pub aprskh_plnprc: String,
+        /// 주식매수청구권에 관한 사항(지급예정시기, 지급방법)
+        pub aprskh_pym_plpd_mth: String,
+        /// 주식매수청구권에 관한 사항(주식매수청구권 제한 관련 내용)
+        pub aprskh_lmt: String,
+        /// 주식매수청구권에 관한 사항(계약에 미치는 효력)
+        pub aprskh_ctref: String,
+        /// 이사회결의일(결정일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub bddd: Reported<NaiveDate>,
+        /// 사외이사 참석여부(참석)
+        #[serde(default, deserialize_with = "amount")]
+        pub od_a_at_t: Reported<i64>,
+        /// 사외이사 참석여부(불참)
+        #[serde(default, deserialize_with = "amount")]
+        pub od_a_at_b: Reported<i64>,
+        /// 감사(사외이사가 아닌 감사위원) 참석여부
+        pub adt_a_atn: String,
+        /// 풋옵션 등 계약 체결여부
+        pub popt_ctr_atn: String,
+        /// 계약내용
+        pub popt_ctr_cn: String,
+        /// 증권신고서 제출대상 여부
+        pub rs_sm_atn: String,
+        /// 제출을 면제받은 경우 그 사유
+        pub ex_sm_r: String,
+    }
+}
+
+impl CmpMgDecsnElement {
+    /// 합병비율, parsed from `mg_rt`
+    pub fn merger_ratio(&self) -> Option<ExchangeRatio> {
+        self.mg_rt.parse().ok()
+    }
+
+    /// 외부평가에 관한 사항
+    pub fn external_valuation(&self) -> ExternalValuation<'_> {
+        ExternalValuation::new(
+            &self.exevl_atn,
+            &self.exevl_bs_rs,
+            &self.exevl_intn,
+            &self.exevl_pd,
+            &self.exevl_op,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de(mock::yyyymmdd())
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build CmpMgDecsnRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bgn_de, mock::yyyymmdd());
+        assert_eq!(params.end_de, "20231231");
+    }
+
+    #[tokio::test]
+    async fn invalid_params_should_fail_before_making_request() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de("invalid")
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build CmpMgDecsnRequestParams");
+
+        let api = OpenDartApi::default();
+        let response = api.get_cmp_mg_decsn(params).await;
+        assert!(response.is_err());
+    }
+}
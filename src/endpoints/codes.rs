@@ -0,0 +1,292 @@
+//! Codes shared by the request parameters and response bodies of several endpoints.
+//!
+//! Each code serializes to and displays as the value OpenDart uses, e.g. `ReprtCode::Annual` as `11011`.
+
+use crate::error::ParseCodeError;
+use std::str::FromStr;
+
+macro_rules! code_enum {
+    (
+        $(#[$enum_attr:meta])*
+        $enum_name:ident {
+            $(
+                $(#[$variant_attr:meta])*
+                $variant:ident = $code:tt => $description:tt, $description_eng:tt,
+            )*
+        }
+    ) => {
+        $(#[$enum_attr])*
+        #[derive(
+            std::fmt::Debug,
+            Clone,
+            Copy,
+            Eq,
+            PartialEq,
+            Ord,
+            PartialOrd,
+            Hash,
+            // derive_more
+            derive_more::Display,
+            // serde
+            serde::Serialize,
+            serde::Deserialize,
+        )]
+        pub enum $enum_name {
+            $(
+                $(#[$variant_attr])*
+                #[doc = $description_eng]
+                #[doc = ""]
+                #[doc = $description]
+                #[display($code)]
+                #[serde(rename = $code)]
+                $variant,
+            )*
+        }
+
+        impl $enum_name {
+            pub const ALL: &'static [$enum_name] = &[$($enum_name::$variant),*];
+
+            /// The code as OpenDart sends and expects it
+            pub fn code(&self) -> &'static str {
+                match self {
+                    $($enum_name::$variant => $code,)*
+                }
+            }
+
+            /// The Korean description of the code, as in the OpenDart guide
+            pub fn description(&self) -> &'static str {
+                match self {
+                    $($enum_name::$variant => $description,)*
+                }
+            }
+
+            /// The English description of the code
+            pub fn description_eng(&self) -> &'static str {
+                match self {
+                    $($enum_name::$variant => $description_eng,)*
+                }
+            }
+        }
+
+        impl FromStr for $enum_name {
+            type Err = ParseCodeError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::ALL
+                    .iter()
+                    .find(|code| code.code() == s)
+                    .copied()
+                    .ok_or_else(|| ParseCodeError {
+                        value: s.to_string(),
+                    })
+            }
+        }
+    };
+}
+
+code_enum! {
+    #[doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/docs/corp_cls.md"))]
+    CorpCls {
+        Kospi = "Y" => "유가", "KOSPI",
+        Kosdaq = "K" => "코스닥", "KOSDAQ",
+        Konex = "N" => "코넥스", "KONEX",
+        Other = "E" => "기타", "Other corporations",
+    }
+}
+
+code_enum! {
+    #[doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/docs/fs_div.md"))]
+    FsDiv {
+        Separate = "OFS" => "재무제표", "Separate financial statements",
+        Consolidated = "CFS" => "연결재무제표", "Consolidated financial statements",
+    }
+}
+
+code_enum! {
+    #[doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/docs/reprt_code.md"))]
+    ReprtCode {
+        FirstQuarter = "11013" => "1분기보고서", "First quarter report",
+        HalfYear = "11012" => "반기보고서", "Half-year report",
+        ThirdQuarter = "11014" => "3분기보고서", "Third quarter report",
+        Annual = "11011" => "사업보고서", "Annual report",
+    }
+}
+
+code_enum! {
+    #[doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/docs/idx_cl_code.md"))]
+    IdxClCode {
+        Profitability = "M210000" => "수익성지표", "Profitability indices",
+        Stability = "M220000" => "안정성지표", "Stability indices",
+        Growth = "M230000" => "성장성지표", "Growth indices",
+        Activity = "M240000" => "활동성지표", "Activity indices",
+    }
+}
+
+code_enum! {
+    #[doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/docs/sort.md"))]
+    Sort {
+        Date = "date" => "접수일자", "Filing date",
+        Company = "crp" => "회사명", "Company name",
+        Report = "rpt" => "보고서명", "Report name",
+    }
+}
+
+code_enum! {
+    #[doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/docs/sort_mth.md"))]
+    SortMth {
+        Asc = "asc" => "오름차순", "Ascending",
+        Desc = "desc" => "내림차순", "Descending",
+    }
+}
+
+code_enum! {
+    #[doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/docs/pblntf_ty.md"))]
+    PblntfTy {
+        Periodic = "A" => "정기공시", "Periodic disclosures",
+        MajorIssues = "B" => "주요사항보고", "Major issue reports",
+        Issuance = "C" => "발행공시", "Issuance disclosures",
+        Shareholding = "D" => "지분공시", "Shareholding disclosures",
+        Other = "E" => "기타공시", "Other disclosures",
+        ExternalAudit = "F" => "외부감사관련", "External audit disclosures",
+        Fund = "G" => "펀드공시", "Fund disclosures",
+        AssetBacked = "H" => "자산유동화", "Asset-backed securities disclosures",
+        Exchange = "I" => "거래소공시", "Exchange disclosures",
+        FairTrade = "J" => "공정위공시", "Fair Trade Commission disclosures",
+    }
+}
+
+code_enum! {
+    #[doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/docs/pblntf_detail_ty.md"))]
+    PblntfDetailTy {
+        A001 = "A001" => "사업보고서", "Annual report",
+        A002 = "A002" => "반기보고서", "Half-year report",
+        A003 = "A003" => "분기보고서", "Quarterly report",
+        A004 = "A004" => "등록법인결산서류(자본시장법이전)", "Settlement documents of registered corporations (before the Capital Markets Act)",
+        A005 = "A005" => "소액공모법인결산서류", "Settlement documents of small public offering corporations",
+        B001 = "B001" => "주요사항보고서", "Major issue report",
+        B002 = "B002" => "주요경영사항신고(자본시장법 이전)", "Report on major management matters (before the Capital Markets Act)",
+        B003 = "B003" => "최대주주등과의거래신고(자본시장법 이전)", "Report on transactions with the largest shareholder, etc. (before the Capital Markets Act)",
+        C001 = "C001" => "증권신고(지분증권)", "Securities registration statement (equity securities)",
+        C002 = "C002" => "증권신고(채무증권)", "Securities registration statement (debt securities)",
+        C003 = "C003" => "증권신고(파생결합증권)", "Securities registration statement (derivative-linked securities)",
+        C004 = "C004" => "증권신고(합병등)", "Securities registration statement (mergers, etc.)",
+        C005 = "C005" => "증권신고(기타)", "Securities registration statement (other)",
+        C006 = "C006" => "소액공모(지분증권)", "Small public offering (equity securities)",
+        C007 = "C007" => "소액공모(채무증권)", "Small public offering (debt securities)",
+        C008 = "C008" => "소액공모(파생결합증권)", "Small public offering (derivative-linked securities)",
+        C009 = "C009" => "소액공모(합병등)", "Small public offering (mergers, etc.)",
+        C010 = "C010" => "소액공모(기타)", "Small public offering (other)",
+        C011 = "C011" => "호가중개시스템을통한소액매출", "Small sale through the quotation brokerage system",
+        D001 = "D001" => "주식등의대량보유상황보고서", "Report on the status of large shareholdings",
+        D002 = "D002" => "임원ㆍ주요주주특정증권등소유상황보고서", "Report on specified securities owned by executives and major shareholders",
+        D003 = "D003" => "의결권대리행사권유", "Solicitation of proxy voting",
+        D004 = "D004" => "공개매수", "Tender offer",
+        D005 = "D005" => "임원ㆍ주요주주 특정증권등 거래계획보고서", "Trading plan report on specified securities of executives and major shareholders",
+        E001 = "E001" => "자기주식취득/처분", "Acquisition/disposal of treasury stock",
+        E002 = "E002" => "신탁계약체결/해지", "Conclusion/termination of trust contracts",
+        E003 = "E003" => "합병등종료보고서", "Completion report of mergers, etc.",
+        E004 = "E004" => "주식매수선택권부여에관한신고", "Report on the grant of stock options",
+        E005 = "E005" => "사외이사에관한신고", "Report on outside directors",
+        E006 = "E006" => "주주총회소집보고서", "Report on the convocation of a general meeting of shareholders",
+        E007 = "E007" => "시장조성/안정조작", "Market making/stabilization",
+        E008 = "E008" => "합병등신고서(자본시장법 이전)", "Merger registration statement, etc. (before the Capital Markets Act)",
+        E009 = "E009" => "금융위등록/취소(자본시장법 이전)", "Registration/cancellation with the Financial Services Commission (before the Capital Markets Act)",
+        F001 = "F001" => "감사보고서", "Audit report",
+        F002 = "F002" => "연결감사보고서", "Consolidated audit report",
+        F003 = "F003" => "결합감사보고서", "Combined audit report",
+        F004 = "F004" => "회계법인사업보고서", "Business report of accounting firms",
+        F005 = "F005" => "감사전재무제표미제출신고서", "Report on the failure to submit financial statements before audit",
+        G001 = "G001" => "증권신고(집합투자증권-신탁형)", "Securities registration statement (collective investment securities, trust type)",
+        G002 = "G002" => "증권신고(집합투자증권-회사형)", "Securities registration statement (collective investment securities, company type)",
+        G003 = "G003" => "증권신고(집합투자증권-합병)", "Securities registration statement (collective investment securities, merger)",
+        H001 = "H001" => "자산유동화계획/양도등록", "Asset securitization plan/transfer registration",
+        H002 = "H002" => "사업/반기/분기보고서", "Annual/half-year/quarterly report",
+        H003 = "H003" => "증권신고(유동화증권등)", "Securities registration statement (securitization securities, etc.)",
+        H004 = "H004" => "채권유동화계획/양도등록", "Bond securitization plan/transfer registration",
+        H005 = "H005" => "자산유동화관련중요사항발생등보고", "Report on material events related to asset securitization",
+        H006 = "H006" => "주요사항보고서", "Major issue report",
+        I001 = "I001" => "수시공시", "Timely disclosure",
+        I002 = "I002" => "공정공시", "Fair disclosure",
+        I003 = "I003" => "시장조치/안내", "Market measures/notices",
+        I004 = "I004" => "지분공시", "Shareholding disclosure",
+        I005 = "I005" => "증권투자회사", "Securities investment company",
+        I006 = "I006" => "채권공시", "Bond disclosure",
+        J001 = "J001" => "대규모내부거래관련", "Large-scale internal transactions",
+        J002 = "J002" => "대규모내부거래관련(구)", "Large-scale internal transactions (old)",
+        J004 = "J004" => "기업집단현황공시", "Business group status disclosure",
+        J005 = "J005" => "비상장회사중요사항공시", "Material matters of unlisted companies",
+        J006 = "J006" => "기타공정위공시", "Other Fair Trade Commission disclosures",
+        J008 = "J008" => "대규모내부거래관련(공익법인용)", "Large-scale internal transactions (public-interest corporations)",
+        J009 = "J009" => "하도급대금결제조건공시", "Payment terms of subcontracts",
+    }
+}
+
+impl PblntfDetailTy {
+    /// The 공시유형 this detailed type belongs to
+    pub fn pblntf_ty(&self) -> PblntfTy {
+        let prefix = &self.code()[..1];
+        PblntfTy::from_str(prefix).expect("Every detailed type starts with a 공시유형 code")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codes_should_round_trip_through_serde_and_from_str() {
+        for code in ReprtCode::ALL {
+            let serialized = serde_json::to_string(code).unwrap();
+            assert_eq!(serialized, format!("\"{}\"", code.code()));
+            assert_eq!(
+                serde_json::from_str::<ReprtCode>(&serialized).unwrap(),
+                *code
+            );
+            assert_eq!(ReprtCode::from_str(&code.to_string()).unwrap(), *code);
+        }
+
+        assert_eq!(ReprtCode::Annual.to_string(), "11011");
+        assert_eq!(FsDiv::Consolidated.to_string(), "CFS");
+        assert_eq!(CorpCls::from_str("K").unwrap(), CorpCls::Kosdaq);
+        assert_eq!(SortMth::Desc.description(), "내림차순");
+        assert_eq!(SortMth::Desc.description_eng(), "Descending");
+        assert_eq!(IdxClCode::from_str("M230000").unwrap(), IdxClCode::Growth);
+        assert!(ReprtCode::from_str("invalid").is_err());
+        assert!(serde_json::from_str::<FsDiv>(r#""invalid""#).is_err());
+    }
+
+    #[test]
+    fn pblntf_detail_ty_should_belong_to_its_pblntf_ty() {
+        assert_eq!(PblntfDetailTy::ALL.len(), 60);
+        for detail in PblntfDetailTy::ALL {
+            assert_eq!(detail.pblntf_ty().code(), &detail.code()[..1]);
+        }
+        assert_eq!(PblntfDetailTy::F001.pblntf_ty(), PblntfTy::ExternalAudit);
+    }
+
+    #[test]
+    fn codes_should_have_english_descriptions() {
+        fn assert_described<T>(codes: &[T], description_eng: fn(&T) -> &'static str) {
+            for code in codes {
+                assert!(!description_eng(code).is_empty());
+            }
+        }
+
+        assert_described(CorpCls::ALL, CorpCls::description_eng);
+        assert_described(FsDiv::ALL, FsDiv::description_eng);
+        assert_described(ReprtCode::ALL, ReprtCode::description_eng);
+        assert_described(IdxClCode::ALL, IdxClCode::description_eng);
+        assert_described(Sort::ALL, Sort::description_eng);
+        assert_described(SortMth::ALL, SortMth::description_eng);
+        assert_described(PblntfTy::ALL, PblntfTy::description_eng);
+        assert_described(PblntfDetailTy::ALL, PblntfDetailTy::description_eng);
+
+        assert_eq!(ReprtCode::Annual.description_eng(), "Annual report");
+        assert_eq!(
+            PblntfTy::ExternalAudit.description_eng(),
+            "External audit disclosures"
+        );
+        assert_eq!(PblntfDetailTy::F001.description(), "감사보고서");
+        assert_eq!(PblntfDetailTy::F001.description_eng(), "Audit report");
+    }
+}
@@ -5,10 +5,12 @@
 
 use crate::client::OpenDartApi;
 use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::CorpCls;
+use crate::endpoints::de::optional_identifier;
+use crate::endpoints::identifiers::{CorpCode, StockCode};
 use crate::endpoints::macros::{json_body, params};
 use crate::endpoints::OpenDartResponse;
 use crate::error::OpenDartError;
-use crate::validate::fields::corp_code;
 
 impl OpenDartApi {
     pub async fn get_company(
@@ -20,18 +22,18 @@ impl OpenDartApi {
 }
 
 params!(
-    #[validate(custom(function = "corp_code"))]
-    pub corp_code: String,
+    pub corp_code: CorpCode,
 );
 
 json_body!(Company {
-    corp_code: String,
+    corp_code: CorpCode,
     corp_name: String,
     corp_name_eng: String,
     stock_name: String,
-    stock_code: String,
+    #[serde(default, deserialize_with = "optional_identifier")]
+    stock_code: Option<StockCode>,
     ceo_nm: String,
-    corp_cls: String,
+    corp_cls: CorpCls,
     jurir_no: String,
     bizr_no: String,
     adres: String,
@@ -65,6 +67,21 @@ mod tests {
         assert_eq!(params.corp_code, corp_code);
     }
 
+    #[tokio::test]
+    async fn invalid_params_should_fail_before_making_request() {
+        assert!(CorpCode::new("invalid").is_err());
+
+        let mut params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .build()
+            .expect("Failed to build CompanyRequestParams");
+        params.crtfc_key = "invalid".to_string();
+
+        let api = OpenDartApi::default();
+        let response = api.get_company(params).await;
+        assert!(response.is_err());
+    }
+
     #[tokio::test]
     #[tracing::instrument]
     async fn get_company_default() {
@@ -148,16 +165,4 @@ mod tests {
         }
         // endregion
     }
-
-    #[tokio::test]
-    async fn invalid_params_should_fail_before_making_request() {
-        let params = ParamsBuilder::default()
-            .corp_code("invalid".to_string())
-            .build()
-            .expect("Failed to build CompanyRequestParams");
-
-        let api = OpenDartApi::default();
-        let response = api.get_company(params).await;
-        assert!(response.is_err());
-    }
 }
@@ -0,0 +1,297 @@
+//! Combines the shareholder endpoints of a periodic report into a view of who controls a company.
+//!
+//! - [`hyslr_sttus`](crate::endpoints::hyslr_sttus): 최대주주 현황
+//! - [`hyslr_chg_sttus`](crate::endpoints::hyslr_chg_sttus): 최대주주 변동현황
+//! - [`mrhl_sttus`](crate::endpoints::mrhl_sttus): 소액주주 현황
+//!
+//! Each set of responses describes a single reporting period,
+//! so a history can be built by requesting successive `bsns_year`/`reprt_code` pairs.
+
+use crate::endpoints::hyslr_chg_sttus::{HyslrChgSttus, HyslrChgSttusElement};
+use crate::endpoints::hyslr_sttus::{HyslrSttus, HyslrSttusElement};
+use crate::endpoints::mrhl_sttus::{MrhlSttus, MrhlSttusElement};
+use crate::endpoints::share_class::ShareClass;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use std::collections::BTreeMap;
+
+/// Control of a company at the end of a reporting period.
+#[derive(std::fmt::Debug, Clone, Default, Eq, PartialEq)]
+pub struct Control<'a> {
+    pub corp_code: &'a str,
+    /// 결산기준일
+    pub stlm_dt: Option<NaiveDate>,
+    /// The largest shareholder and its related parties, without the total rows
+    pub related_parties: Vec<&'a HyslrSttusElement>,
+    /// Total (`계`) rows of the largest shareholder and its related parties
+    pub totals: Vec<&'a HyslrSttusElement>,
+    /// Changes of the largest shareholder during the period
+    pub changes: Vec<&'a HyslrChgSttusElement>,
+    /// Minority shareholders
+    pub minority: Vec<&'a MrhlSttusElement>,
+}
+
+impl<'a> Control<'a> {
+    /// Groups the responses of the three endpoints by `corp_code`.
+    ///
+    /// The responses are expected to be for the same `bsns_year` and `reprt_code`.
+    pub fn by_corp_code(
+        hyslr_sttus: &'a HyslrSttus,
+        hyslr_chg_sttus: &'a HyslrChgSttus,
+        mrhl_sttus: &'a MrhlSttus,
+    ) -> BTreeMap<&'a str, Control<'a>> {
+        let mut controls: BTreeMap<&str, Control> = BTreeMap::new();
+
+        for element in &hyslr_sttus.list {
+            let control = entry(
+                &mut controls,
+                element.corp_code.as_str(),
+                element.stlm_dt.value(),
+            );
+            if element.is_total() {
+                control.totals.push(element);
+            } else {
+                control.related_parties.push(element);
+            }
+        }
+        for element in &hyslr_chg_sttus.list {
+            entry(
+                &mut controls,
+                element.corp_code.as_str(),
+                element.stlm_dt.value(),
+            )
+            .changes
+            .push(element);
+        }
+        for element in &mrhl_sttus.list {
+            entry(
+                &mut controls,
+                element.corp_code.as_str(),
+                element.stlm_dt.value(),
+            )
+            .minority
+            .push(element);
+        }
+
+        controls
+    }
+
+    /// The largest shareholder itself, as reported in the common share rows.
+    ///
+    /// Falls back to the related party with the most common shares at the end of the period
+    /// when no row is marked as `본인`.
+    pub fn largest_shareholder(&self) -> Option<&'a HyslrSttusElement> {
+        common(&self.related_parties)
+            .find(|element| element.is_largest_shareholder())
+            .or_else(|| {
+                common(&self.related_parties)
+                    .max_by_key(|element| element.trmend_posesn_stock_co.value())
+            })
+    }
+
+    /// Name of the largest shareholder.
+    ///
+    /// The latest change during the period by `change_on` takes precedence,
+    /// since it names the shareholder directly.
+    /// Changes without a date only count when no change is dated, in which case the last one reported is used.
+    pub fn largest_shareholder_name(&self) -> Option<&'a str> {
+        self.changes
+            .iter()
+            .max_by_key(|change| change.change_on.value())
+            .map(|change| change.mxmm_shrholdr_nm.as_str())
+            .or_else(|| {
+                self.largest_shareholder()
+                    .map(|element| element.nm.as_str())
+            })
+    }
+
+    /// Ownership ratio (%) of the common shares held by the largest shareholder
+    /// and its related parties at the end of the period.
+    ///
+    /// Each class of shares has its own ratio, so rows of other classes are left out.
+    /// Uses the total row when reported, and sums the individual rows otherwise.
+    pub fn related_party_ratio(&self) -> Option<Decimal> {
+        let rows = if common(&self.totals).next().is_none() {
+            &self.related_parties
+        } else {
+            &self.totals
+        };
+        let mut rows = common(rows).peekable();
+        rows.peek()?;
+        rows.map(|element| element.trmend_posesn_stock_qota_rt.value())
+            .sum()
+    }
+}
+
+fn common<'a, 'b>(
+    rows: &'b [&'a HyslrSttusElement],
+) -> impl Iterator<Item = &'a HyslrSttusElement> + 'b {
+    rows.iter()
+        .copied()
+        .filter(|element| element.stock_knd == ShareClass::Common)
+}
+
+fn entry<'a, 'b>(
+    controls: &'b mut BTreeMap<&'a str, Control<'a>>,
+    corp_code: &'a str,
+    stlm_dt: Option<NaiveDate>,
+) -> &'b mut Control<'a> {
+    let control = controls.entry(corp_code).or_insert_with(|| Control {
+        corp_code,
+        ..Control::default()
+    });
+    if control.stlm_dt.is_none() {
+        control.stlm_dt = stlm_dt;
+    }
+    control
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::reported::Reported;
+    use crate::test_utils::mock;
+
+    fn hyslr(nm: &str, relate: &str, shares: i64, ratio: &str) -> HyslrSttusElement {
+        hyslr_of(ShareClass::Common, nm, relate, shares, ratio)
+    }
+
+    fn hyslr_of(
+        stock_knd: ShareClass,
+        nm: &str,
+        relate: &str,
+        shares: i64,
+        ratio: &str,
+    ) -> HyslrSttusElement {
+        HyslrSttusElement {
+            rcept_no: mock::rcept_no(),
+            corp_cls: mock::corp_cls(),
+            corp_code: mock::corp_code(),
+            corp_name: mock::corp_name(),
+            nm: nm.to_string(),
+            relate: relate.to_string(),
+            stock_knd,
+            bsis_posesn_stock_co: Reported::Parsed(shares),
+            bsis_posesn_stock_qota_rt: Reported::Parsed(ratio.parse().unwrap()),
+            trmend_posesn_stock_co: Reported::Parsed(shares),
+            trmend_posesn_stock_qota_rt: Reported::Parsed(ratio.parse().unwrap()),
+            rm: "-".to_string(),
+            stlm_dt: NaiveDate::from_ymd_opt(2023, 12, 31).into(),
+        }
+    }
+
+    fn change(mxmm_shrholdr_nm: &str, change_on: &str) -> serde_json::Value {
+        serde_json::json!({
+            "rcept_no": "20240312000736",
+            "corp_cls": "Y",
+            "corp_code": "00126380",
+            "corp_name": "삼성전자",
+            "change_on": change_on,
+            "mxmm_shrholdr_nm": mxmm_shrholdr_nm,
+            "posesn_stock_co": "97,414,196",
+            "qota_rt": "1.63",
+            "change_cause": "-",
+            "rm": "-",
+            "stlm_dt": "2023-12-31",
+        })
+    }
+
+    fn body<T: serde::de::DeserializeOwned>(list: impl serde::Serialize) -> T {
+        serde_json::from_value(serde_json::json!({
+            "status": "000",
+            "message": "정상",
+            "list": list,
+        }))
+        .expect("Failed to deserialize response body")
+    }
+
+    #[test]
+    fn by_corp_code_should_combine_shareholder_endpoints() {
+        let hyslr_sttus: HyslrSttus = body(vec![
+            hyslr("이재용", "본인", 97_414_196, "1.63"),
+            hyslr("삼성생명보험", "계열회사", 508_157_148, "8.51"),
+            hyslr("계", "-", 605_571_344, "10.14"),
+        ]);
+        let hyslr_chg_sttus: HyslrChgSttus = body(Vec::<HyslrChgSttusElement>::new());
+        let mrhl_sttus: MrhlSttus = body(Vec::<MrhlSttusElement>::new());
+
+        let controls = Control::by_corp_code(&hyslr_sttus, &hyslr_chg_sttus, &mrhl_sttus);
+        let control = &controls[mock::corp_code().as_str()];
+
+        assert_eq!(control.related_parties.len(), 2);
+        assert_eq!(control.totals.len(), 1);
+        assert_eq!(control.largest_shareholder_name(), Some("이재용"));
+        assert_eq!(
+            control.related_party_ratio(),
+            Some("10.14".parse().unwrap())
+        );
+        assert_eq!(control.stlm_dt, NaiveDate::from_ymd_opt(2023, 12, 31));
+    }
+
+    #[test]
+    fn control_should_only_count_common_shares() {
+        let hyslr_sttus: HyslrSttus = body(vec![
+            hyslr("이재용", "본인", 97_414_196, "1.63"),
+            hyslr("삼성생명보험", "계열회사", 508_157_148, "8.51"),
+            hyslr_of(
+                ShareClass::Preferred,
+                "삼성복지재단",
+                "재단",
+                900_000_000,
+                "0.10",
+            ),
+            hyslr("계", "-", 605_571_344, "10.14"),
+            hyslr_of(ShareClass::Preferred, "계", "-", 900_000_000, "0.10"),
+        ]);
+        let hyslr_chg_sttus: HyslrChgSttus = body(Vec::<HyslrChgSttusElement>::new());
+        let mrhl_sttus: MrhlSttus = body(Vec::<MrhlSttusElement>::new());
+
+        let controls = Control::by_corp_code(&hyslr_sttus, &hyslr_chg_sttus, &mrhl_sttus);
+        let control = &controls[mock::corp_code().as_str()];
+
+        assert_eq!(control.totals.len(), 2);
+        assert_eq!(
+            control.related_party_ratio(),
+            Some("10.14".parse().unwrap())
+        );
+
+        let mut without_totals = control.clone();
+        without_totals.totals.clear();
+        assert_eq!(
+            without_totals.related_party_ratio(),
+            Some("10.14".parse().unwrap())
+        );
+
+        let mut without_largest = control.clone();
+        without_largest
+            .related_parties
+            .retain(|element| !element.is_largest_shareholder());
+        assert_eq!(
+            without_largest
+                .largest_shareholder()
+                .map(|element| element.nm.as_str()),
+            Some("삼성생명보험")
+        );
+    }
+
+    #[test]
+    fn largest_shareholder_name_should_use_the_latest_change() {
+        let hyslr_sttus: HyslrSttus = body(vec![hyslr("이재용", "본인", 97_414_196, "1.63")]);
+        let hyslr_chg_sttus: HyslrChgSttus = body(vec![
+            change("삼성물산", "2023.11.01"),
+            change("이건희", "2020.10.25"),
+            change("-", "-"),
+        ]);
+        let mrhl_sttus: MrhlSttus = body(Vec::<MrhlSttusElement>::new());
+
+        let controls = Control::by_corp_code(&hyslr_sttus, &hyslr_chg_sttus, &mrhl_sttus);
+        let control = &controls[mock::corp_code().as_str()];
+
+        assert_eq!(
+            control.changes[0].change_on,
+            NaiveDate::from_ymd_opt(2023, 11, 1).into()
+        );
+        assert_eq!(control.changes[2].change_on, Reported::Empty);
+        assert_eq!(control.largest_shareholder_name(), Some("삼성물산"));
+    }
+}
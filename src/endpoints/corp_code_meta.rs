@@ -1,5 +1,6 @@
 use crate::client::OpenDartApi;
-use crate::endpoints::macros::derive_common;
+use crate::endpoints::identifiers::{CorpCode, StockCode};
+use crate::endpoints::macros::{derive_common, params};
 use crate::error::{OpenDartError, UnexpectedZipContentError};
 use crate::utils::derive_newtype;
 use bytes::Bytes;
@@ -14,13 +15,16 @@ impl OpenDartApi {
     #[tracing::instrument(skip(self))]
     pub async fn get_corp_codes(&self) -> Result<CorpMetas, OpenDartError> {
         let url = self.url("/api/corpCode.xml");
-        let bytes = self.get_zip(url).await?;
+        let params = ParamsBuilder::default().build()?;
+        let bytes = self.get_zip(url, params).await?;
         let cursor = Cursor::new(bytes);
         let mut zip = ZipArchive::new(cursor)?;
         CorpMetas::from_zip(&mut zip)
     }
 }
 
+params!();
+
 derive_newtype! {
     /// ## 기업 코드 정보
     #[display("{_0:?}")]
@@ -28,9 +32,9 @@ derive_newtype! {
 }
 
 derive_common!(CorpCodeMeta {
-    corp_code: String,
+    corp_code: CorpCode,
     corp_name: String,
-    stock_code: String,
+    stock_code: Option<StockCode>,
     modify_date: String
 });
 
@@ -61,11 +65,24 @@ impl TryFrom<CorpCodeMetaOptional> for CorpCodeMeta {
             OpenDartError::from(err)
         };
 
+        let corp_code = CorpCode::new(optional.corp_code.ok_or(validation_error("corp_code"))?)?;
+        // stock_code is empty for unlisted companies,
+        // and a malformed one shouldn't fail the whole list of companies
+        let stock_code = optional
+            .stock_code
+            .filter(|stock_code| !stock_code.trim().is_empty())
+            .and_then(|stock_code| {
+                StockCode::new(&stock_code)
+                    .inspect_err(
+                        |_e| tracing::warn!(%corp_code, stock_code, "Skipping invalid stock_code"),
+                    )
+                    .ok()
+            });
+
         Ok(Self {
-            corp_code: optional.corp_code.ok_or(validation_error("corp_code"))?,
+            corp_code,
             corp_name: optional.corp_name.ok_or(validation_error("corp_name"))?,
-            // stock_code is optional
-            stock_code: optional.stock_code.unwrap_or_default(),
+            stock_code,
             modify_date: optional
                 .modify_date
                 .ok_or(validation_error("modify_date"))?,
@@ -223,21 +240,49 @@ mod tests {
             corp_infos,
             CorpMetas(vec![
                 CorpCodeMeta {
-                    corp_code: "00126380".to_string(),
+                    corp_code: CorpCode::new("00126380").unwrap(),
                     corp_name: "삼성전자(주)".to_string(),
-                    stock_code: "005930".to_string(),
+                    stock_code: StockCode::new("005930").ok(),
                     modify_date: "20210531".to_string(),
                 },
                 CorpCodeMeta {
-                    corp_code: "00164779".to_string(),
+                    corp_code: CorpCode::new("00164779").unwrap(),
                     corp_name: "삼성전자서비스(주)".to_string(),
-                    stock_code: "012057".to_string(),
+                    stock_code: StockCode::new("012057").ok(),
                     modify_date: "20210531".to_string(),
                 }
             ])
         );
     }
 
+    #[test]
+    fn read_xml_should_skip_invalid_stock_codes() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<result>
+    <list>
+        <corp_code>00126380</corp_code>
+        <corp_name>삼성전자(주)</corp_name>
+        <stock_code>05930</stock_code>
+        <modify_date>20210531</modify_date>
+    </list>
+    <list>
+        <corp_code>00164779</corp_code>
+        <corp_name>삼성전자서비스(주)</corp_name>
+        <stock_code> </stock_code>
+        <modify_date>20210531</modify_date>
+    </list>
+</result>"#;
+
+        let corp_infos = CorpMetas::from_reader(xml.as_bytes()).unwrap();
+
+        assert_eq!(corp_infos.0.len(), 2);
+        assert!(corp_infos.iter().all(|meta| meta.stock_code.is_none()));
+        assert_eq!(
+            corp_infos.0[0].corp_code,
+            CorpCode::new("00126380").unwrap()
+        );
+    }
+
     #[test]
     fn read_xml_should_error_when_there_are_more_than_one_file() {
         let zip = create_mock_zip_bytes(2);
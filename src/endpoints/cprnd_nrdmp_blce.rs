@@ -0,0 +1,181 @@
+//! # 회사채 미상환 잔액
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS002&apiId=2020006>
+//!
+//! 정기보고서(사업, 분기, 반기보고서) 내에 회사채 미상환 잔액 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::{CorpCls, ReprtCode};
+use crate::endpoints::de::{money, optional_date};
+use crate::endpoints::identifiers::{BsnsYear, CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::maturity::{Maturity, MaturityBalance, MaturityBucket};
+use crate::endpoints::money::Money;
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use chrono::NaiveDate;
+
+impl OpenDartApi {
+    pub async fn get_cprnd_nrdmp_blce(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<CprndNrdmpBlce>>, OpenDartError> {
+        self.get(self.url("/api/cprndNrdmpBlce.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    pub bsns_year: BsnsYear,
+    pub reprt_code: ReprtCode,
+);
+
+json_body!(CprndNrdmpBlce {
+    pub list: Vec<CprndNrdmpBlceElement>,
+});
+
+derive_common! {
+    CprndNrdmpBlceElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 잔여만기 (e.g. 공모, 사모, 합계)
+        pub remndr_exprtn1: String,
+        /// 잔여만기 (e.g. 미상환 잔액)
+        pub remndr_exprtn2: String,
+        /// 1년 이하
+        #[serde(default, deserialize_with = "money")]
+        pub yy1_below: Reported<Money>,
+        /// 1년 초과 2년 이하
+        #[serde(default, deserialize_with = "money")]
+        pub yy1_excess_yy2_below: Reported<Money>,
+        /// 2년 초과 3년 이하
+        #[serde(default, deserialize_with = "money")]
+        pub yy2_excess_yy3_below: Reported<Money>,
+        /// 3년 초과 4년 이하
+        #[serde(default, deserialize_with = "money")]
+        pub yy3_excess_yy4_below: Reported<Money>,
+        /// 4년 초과 5년 이하
+        #[serde(default, deserialize_with = "money")]
+        pub yy4_excess_yy5_below: Reported<Money>,
+        /// 5년 초과 10년 이하
+        #[serde(default, deserialize_with = "money")]
+        pub yy5_excess_yy10_below: Reported<Money>,
+        /// 10년 초과
+        #[serde(default, deserialize_with = "money")]
+        pub yy10_excess: Reported<Money>,
+        /// 합계
+        #[serde(default, deserialize_with = "money")]
+        pub sm: Reported<Money>,
+        /// 결산기준일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub stlm_dt: Reported<NaiveDate>,
+    }
+}
+
+impl MaturityBalance for CprndNrdmpBlceElement {
+    fn buckets(&self) -> Vec<MaturityBucket> {
+        use Maturity::Years;
+        vec![
+            MaturityBucket::new(None, Some(Years(1)), self.yy1_below.value()),
+            MaturityBucket::new(
+                Some(Years(1)),
+                Some(Years(2)),
+                self.yy1_excess_yy2_below.value(),
+            ),
+            MaturityBucket::new(
+                Some(Years(2)),
+                Some(Years(3)),
+                self.yy2_excess_yy3_below.value(),
+            ),
+            MaturityBucket::new(
+                Some(Years(3)),
+                Some(Years(4)),
+                self.yy3_excess_yy4_below.value(),
+            ),
+            MaturityBucket::new(
+                Some(Years(4)),
+                Some(Years(5)),
+                self.yy4_excess_yy5_below.value(),
+            ),
+            MaturityBucket::new(
+                Some(Years(5)),
+                Some(Years(10)),
+                self.yy5_excess_yy10_below.value(),
+            ),
+            MaturityBucket::new(Some(Years(10)), None, self.yy10_excess.value()),
+        ]
+    }
+
+    fn sum(&self) -> Option<Money> {
+        self.sm.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::money::KrwUnit;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn buckets_should_follow_maturity_bands() {
+        // A report in 백만원, where only some cells state their own unit
+        let element: CprndNrdmpBlceElement = serde_json::from_value(serde_json::json!({
+            "rcept_no": mock::rcept_no(),
+            "corp_cls": mock::corp_cls(),
+            "corp_code": mock::corp_code(),
+            "corp_name": mock::corp_name(),
+            "remndr_exprtn1": "공모",
+            "remndr_exprtn2": "미상환 잔액",
+            "yy1_below": "100",
+            "yy1_excess_yy2_below": "1,000",
+            "yy2_excess_yy3_below": "-",
+            "yy3_excess_yy4_below": "-",
+            "yy4_excess_yy5_below": "-",
+            "yy5_excess_yy10_below": "-",
+            "yy10_excess": "2억원",
+            "sm": "1,300",
+            "stlm_dt": "2023-12-31",
+        }))
+        .expect("Failed to deserialize CprndNrdmpBlceElement");
+
+        let buckets = element.buckets();
+        assert_eq!(buckets.len(), 7);
+        assert_eq!(buckets[0].up_to, Some(Maturity::Years(1)));
+        assert_eq!(buckets[0].balance, Some(Money::new(100, None)));
+        assert_eq!(buckets[6].over, Some(Maturity::Years(10)));
+        assert_eq!(
+            buckets[6].balance,
+            Some(Money::new(2, Some(KrwUnit::HundredMillion)))
+        );
+        assert_eq!(
+            element.due_within(Maturity::Years(2), KrwUnit::Million),
+            1_100_000_000
+        );
+        assert_eq!(
+            element.sum().and_then(|sum| sum.krw(KrwUnit::Million)),
+            Some(1_300_000_000)
+        );
+    }
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bsns_year(mock::bsns_year())
+            .reprt_code(mock::reprt_code())
+            .build()
+            .expect("Failed to build CprndNrdmpBlceRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bsns_year, mock::bsns_year());
+        assert_eq!(params.reprt_code, mock::reprt_code());
+    }
+}
@@ -0,0 +1,173 @@
+//! # 감자 결정
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS005&apiId=2020026>
+//!
+//! 주요사항보고서 내에 감자 결정 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::CorpCls;
+use crate::endpoints::de::{amount, decimal, krw, optional_date};
+use crate::endpoints::identifiers::{CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use crate::validate::fields::yyyymmdd;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+impl OpenDartApi {
+    pub async fn get_cr_decsn(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<CrDecsn>>, OpenDartError> {
+        self.get(self.url("/api/crDecsn.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub bgn_de: String,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub end_de: String,
+);
+
+json_body!(CrDecsn {
+    pub list: Vec<CrDecsnElement>,
+});
+
+derive_common! {
+    CrDecsnElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 감자주식의 종류와 수(보통주식 (주))
+        #[serde(default, deserialize_with = "amount")]
+        pub crstk_ostk_cnt: Reported<i64>,
+        /// 감자주식의 종류와 수(기타주식 (주))
+        #[serde(default, deserialize_with = "amount")]
+        pub crstk_estk_cnt: Reported<i64>,
+        /// 1주당 액면가액 (원)
+        #[serde(default, deserialize_with = "krw")]
+        pub fv_ps: Reported<i64>,
+        /// 감자전후 자본금(감자전 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub bfcr_cpt: Reported<i64>,
+        /// 감자전후 자본금(감자후 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub atcr_cpt: Reported<i64>,
+        /// 감자전후 발행주식수(보통주식 (주)(감자전))
+        #[serde(default, deserialize_with = "amount")]
+        pub bfcr_tisstk_ostk: Reported<i64>,
+        /// 감자전후 발행주식수(보통주식 (주)(감자후))
+        #[serde(default, deserialize_with = "amount")]
+        pub atcr_tisstk_ostk: Reported<i64>,
+        /// 감자전후 발행주식수(기타주식 (주)(감자전))
+        #[serde(default, deserialize_with = "amount")]
+        pub bfcr_tisstk_estk: Reported<i64>,
+        /// 감자전후 발행주식수(기타주식 (주)(감자후))
+        #[serde(default, deserialize_with = "amount")]
+        pub atcr_tisstk_estk: Reported<i64>,
+        /// 감자비율(보통주식 (%))
+        #[serde(default, deserialize_with = "decimal")]
+        pub cr_rt_ostk: Reported<Decimal>,
+        /// 감자비율(기타주식 (%))
+        #[serde(default, deserialize_with = "decimal")]
+        pub cr_rt_estk: Reported<Decimal>,
+        /// 감자기준일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub cr_std: Reported<NaiveDate>,
+        /// 감자방법
+        pub cr_mth: String,
+        /// 감자사유
+        pub cr_rs: String,
+        /// 감자일정(주주총회 예정일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub crsc_gmtsck_prd: Reported<NaiveDate>,
+        /// 감자일정(명의개서정지기간)
+        pub crsc_trnmsppd: String,
+        /// 감자일정(구주권 제출기간)
+        pub crsc_osprpd: String,
+        /// 감자일정(매매거래 정지예정기간)
+        pub crsc_trspprpd: String,
+        /// 감자일정(구주권 제출기간(시작일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub crsc_osprpd_bgd: Reported<NaiveDate>,
+        /// 감자일정(구주권 제출기간(종료일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub crsc_osprpd_edd: Reported<NaiveDate>,
+        /// 감자일정(매매거래 정지예정기간(시작일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub crsc_trspprpd_bgd: Reported<NaiveDate>,
+        /// 감자일정(매매거래 정지예정기간(종료일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub crsc_trspprpd_edd: Reported<NaiveDate>,
+        /// 감자일정(신주권교부예정일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub crsc_nstkdlprd: Reported<NaiveDate>,
+        /// 감자일정(신주상장예정일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub crsc_nstklstprd: Reported<NaiveDate>,
+        /// 채권자 이의제출기간(시작일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub cdobprpd_bgd: Reported<NaiveDate>,
+        /// 채권자 이의제출기간(종료일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub cdobprpd_edd: Reported<NaiveDate>,
+        /// 구주권제출 및 신주권교부장소
+        pub ospr_nstkdl_pl: String,
+        /// 이사회결의일(결정일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub bddd: Reported<NaiveDate>,
+        /// 사외이사 참석여부(참석)
+        #[serde(default, deserialize_with = "amount")]
+        pub od_a_at_t: Reported<i64>,
+        /// 사외이사 참석여부(불참)
+        #[serde(default, deserialize_with = "amount")]
+        pub od_a_at_b: Reported<i64>,
+        /// 감사(사외이사가 아닌 감사위원) 참석여부
+        pub adt_a_atn: String,
+        /// 공정거래위원회 신고대상 여부
+        pub ftc_stt_atn: String,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de(mock::yyyymmdd())
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build CrDecsnRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bgn_de, mock::yyyymmdd());
+        assert_eq!(params.end_de, "20231231");
+    }
+
+    #[tokio::test]
+    async fn invalid_params_should_fail_before_making_request() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de("invalid")
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build CrDecsnRequestParams");
+
+        let api = OpenDartApi::default();
+        let response = api.get_cr_decsn(params).await;
+        assert!(response.is_err());
+    }
+}
@@ -0,0 +1,252 @@
+//! # 전환사채권 발행결정
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS005&apiId=2020033>
+//!
+//! 주요사항보고서 내에 전환사채권 발행결정 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::CorpCls;
+use crate::endpoints::de::{amount, decimal, krw, optional_date};
+use crate::endpoints::dilution::Mezzanine;
+use crate::endpoints::identifiers::{CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use crate::validate::fields::yyyymmdd;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+impl OpenDartApi {
+    pub async fn get_cvbd_is_decsn(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<CvbdIsDecsn>>, OpenDartError> {
+        self.get(self.url("/api/cvbdIsDecsn.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub bgn_de: String,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub end_de: String,
+);
+
+json_body!(CvbdIsDecsn {
+    pub list: Vec<CvbdIsDecsnElement>,
+});
+
+derive_common! {
+    CvbdIsDecsnElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 사채의 종류(회차)
+        pub bd_tm: String,
+        /// 사채의 종류(종류)
+        pub bd_knd: String,
+        /// 사채의 권면(전자등록)총액 (원)
+        #[serde(default, deserialize_with = "krw")]
+        pub bd_fta: Reported<i64>,
+        /// 정관상 잔여 발행한도 (원)
+        #[serde(default, deserialize_with = "krw")]
+        pub atcsc_rmislmt: Reported<i64>,
+        /// 해외발행(권면(전자등록)총액)
+        #[serde(default, deserialize_with = "krw")]
+        pub ovis_fta: Reported<i64>,
+        /// 해외발행(권면(전자등록)총액(통화단위))
+        pub ovis_fta_crn: String,
+        /// 해외발행(기준환율등)
+        pub ovis_ster: String,
+        /// 해외발행(발행지역)
+        pub ovis_isar: String,
+        /// 해외발행(해외상장시 시장의 명칭)
+        pub ovis_mktnm: String,
+        /// 자금조달의 목적(시설자금 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub fdpp_fclt: Reported<i64>,
+        /// 자금조달의 목적(영업양수자금 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub fdpp_bsninh: Reported<i64>,
+        /// 자금조달의 목적(운영자금 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub fdpp_op: Reported<i64>,
+        /// 자금조달의 목적(채무상환자금 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub fdpp_dtrp: Reported<i64>,
+        /// 자금조달의 목적(타법인 증권 취득자금 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub fdpp_ocsa: Reported<i64>,
+        /// 자금조달의 목적(기타자금 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub fdpp_etc: Reported<i64>,
+        /// 사채의 이율(표면이자율 (%))
+        #[serde(default, deserialize_with = "decimal")]
+        pub bd_intr_ex: Reported<Decimal>,
+        /// 사채의 이율(만기이자율 (%))
+        #[serde(default, deserialize_with = "decimal")]
+        pub bd_intr_sf: Reported<Decimal>,
+        /// 사채만기일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub bd_mtd: Reported<NaiveDate>,
+        /// 사채발행방법
+        pub bdis_mthn: String,
+        /// 전환에 관한 사항(전환비율 (%))
+        #[serde(default, deserialize_with = "decimal")]
+        pub cv_rt: Reported<Decimal>,
+        /// 전환에 관한 사항(전환가액 (원/주))
+        #[serde(default, deserialize_with = "krw")]
+        pub cv_prc: Reported<i64>,
+        /// 전환에 관한 사항(전환에 따라 발행할 주식(종류))
+        pub cvisstk_knd: String,
+        /// 전환에 관한 사항(전환에 따라 발행할 주식(주식수))
+        #[serde(default, deserialize_with = "amount")]
+        pub cvisstk_cnt: Reported<i64>,
+        /// 전환에 관한 사항(전환에 따라 발행할 주식(주식총수 대비 비율(%)))
+        #[serde(default, deserialize_with = "decimal")]
+        pub cvisstk_tisstk_vs: Reported<Decimal>,
+        /// 전환에 관한 사항(전환청구기간(시작일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub cvrqpd_bgd: Reported<NaiveDate>,
+        /// 전환에 관한 사항(전환청구기간(종료일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub cvrqpd_edd: Reported<NaiveDate>,
+        /// 시가하락에 따른 조정가액(최저 조정가액 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub act_mktprcfl_cvprc_lwtrsprc: Reported<i64>,
+        /// 시가하락에 따른 조정가액(최저 조정가액 근거)
+        pub act_mktprcfl_cvprc_lwtrsprc_bs: String,
+        /// 발행당시 전환가액의 70% 미만으로 조정가능한 잔여 발행한도 (원)
+        #[serde(default, deserialize_with = "krw")]
+        pub rmislmt_lt70p: Reported<i64>,
+        /// 합병 관련 사항
+        pub abmg: String,
+        /// 청약일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub sbd: Reported<NaiveDate>,
+        /// 납입일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub pymd: Reported<NaiveDate>,
+        /// 대표주관회사
+        pub rpmcmp: String,
+        /// 보증기관
+        pub grint: String,
+        /// 이사회결의일(결정일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub bddd: Reported<NaiveDate>,
+        /// 사외이사 참석여부(참석)
+        #[serde(default, deserialize_with = "amount")]
+        pub od_a_at_t: Reported<i64>,
+        /// 사외이사 참석여부(불참)
+        #[serde(default, deserialize_with = "amount")]
+        pub od_a_at_b: Reported<i64>,
+        /// 감사(사외이사가 아닌 감사위원) 참석여부
+        pub adt_a_atn: String,
+        /// 증권신고서 제출대상 여부
+        pub rs_sm_atn: String,
+        /// 제출을 면제받은 경우 그 사유
+        pub ex_sm_r: String,
+        /// 당해 사채의 해외발행과 연계된 대차거래 내역
+        pub ovis_ltdtl: String,
+        /// 공정거래위원회 신고대상 여부
+        pub ftc_stt_atn: String,
+    }
+}
+
+impl Mezzanine for CvbdIsDecsnElement {
+    fn face_amount(&self) -> Option<i64> {
+        self.bd_fta.value()
+    }
+
+    fn exercise_price(&self) -> Option<i64> {
+        self.cv_prc.value()
+    }
+
+    fn issuable_shares(&self) -> Option<i64> {
+        self.cvisstk_cnt.value()
+    }
+
+    fn issuable_ratio(&self) -> Option<Decimal> {
+        self.cvisstk_tisstk_vs.value()
+    }
+
+    fn refixing_floor(&self) -> Option<i64> {
+        self.act_mktprcfl_cvprc_lwtrsprc.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn issuable_shares_at_floor_should_use_refixing_floor() {
+        let element: CvbdIsDecsnElement = serde_json::from_value(serde_json::json!({
+            "rcept_no": mock::rcept_no(),
+            "corp_cls": "K",
+            "corp_code": mock::corp_code(),
+            "corp_name": mock::corp_name(),
+            "bd_tm": "3",
+            "bd_knd": "무기명식 이권부 무보증 사모 전환사채",
+            "bd_fta": "10,000,000,000",
+            "ovis_fta_crn": "-",
+            "ovis_ster": "-",
+            "ovis_isar": "-",
+            "ovis_mktnm": "-",
+            "bdis_mthn": "사모",
+            "cv_prc": "5,000",
+            "cvisstk_knd": "기명식 보통주",
+            "cvisstk_cnt": "2,000,000",
+            "act_mktprcfl_cvprc_lwtrsprc": "3,500",
+            "act_mktprcfl_cvprc_lwtrsprc_bs": "전환가액의 70%",
+            "abmg": "-",
+            "rpmcmp": "-",
+            "grint": "-",
+            "adt_a_atn": "-",
+            "rs_sm_atn": "미해당",
+            "ex_sm_r": "사모발행",
+            "ovis_ltdtl": "-",
+            "ftc_stt_atn": "미해당",
+        }))
+        .expect("Failed to deserialize CvbdIsDecsnElement");
+
+        assert_eq!(element.issuable_shares(), Some(2_000_000));
+        assert_eq!(element.issuable_shares_at_floor(), Some(2_857_142));
+    }
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de(mock::yyyymmdd())
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build CvbdIsDecsnRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bgn_de, mock::yyyymmdd());
+        assert_eq!(params.end_de, "20231231");
+    }
+
+    #[tokio::test]
+    async fn invalid_params_should_fail_before_making_request() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de("invalid")
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build CvbdIsDecsnRequestParams");
+
+        let api = OpenDartApi::default();
+        let response = api.get_cvbd_is_decsn(params).await;
+        assert!(response.is_err());
+    }
+}
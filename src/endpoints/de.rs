@@ -0,0 +1,695 @@
+//! Deserialization helpers for values which OpenDart returns as formatted strings.
+//!
+//! The helpers accept both the raw API format (e.g. `"1,234,000"`)
+//! and the already normalized format (e.g. `1234000`),
+//! so that serialized response bodies can be deserialized again.
+//! Text which can't be parsed, such as `미정`, is kept as [`Reported::Raw`].
+
+use crate::endpoints::emp_sttus::Tenure;
+use crate::endpoints::money::{KrwUnit, Money};
+use crate::endpoints::reported::Reported;
+use chrono::NaiveDate;
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::Decimal;
+use serde::de::{self, Deserializer, Visitor};
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+/// Placeholders OpenDart uses in place of an empty value.
+///
+/// Filers type the dash in various widths, so all of them are accepted.
+const EMPTY_PLACEHOLDERS: [&str; 6] = ["", "-", "－", "–", "—", "―"];
+
+/// Deserializes a monetary amount or count such as `"1,234,000"`, `"1,234,000원"` or `"(1,234)"`.
+///
+/// Empty values and placeholders like `"-"` become [`Reported::Empty`].
+pub(crate) fn amount<'de, D>(deserializer: D) -> Result<Reported<i64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(FormattedVisitor::<i64>(PhantomData))
+}
+
+/// Deserializes a monetary amount in KRW such as `"1,234,000"`, `"1,234백만원"` or `"1.5억원"`,
+/// for columns which are documented in 원.
+///
+/// Amounts with a unit suffix are normalized to 원, and amounts without one are taken as 원.
+/// Use [`money`] for columns in the unit of the report.
+/// Empty values and placeholders like `"-"` become [`Reported::Empty`].
+pub(crate) fn krw<'de, D>(deserializer: D) -> Result<Reported<i64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let krw = deserializer.deserialize_any(FormattedVisitor::<Krw>(PhantomData))?;
+    Ok(krw.map(|Krw(amount)| amount))
+}
+
+/// Deserializes an amount of money such as `"73,790"` or `"8,850백만원"`, keeping the unit it's reported in.
+///
+/// Amounts without a unit suffix are in the unit of the report, which the response doesn't include.
+/// Empty values and placeholders like `"-"` become [`Reported::Empty`].
+pub(crate) fn money<'de, D>(deserializer: D) -> Result<Reported<Money>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(FormattedVisitor::<Money>(PhantomData))
+}
+
+/// Deserializes a decimal value such as `"12.34"`, `"1,234.5"` or `"12.3%"`.
+///
+/// Empty values and placeholders like `"-"` become [`Reported::Empty`].
+pub(crate) fn decimal<'de, D>(deserializer: D) -> Result<Reported<Decimal>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(FormattedVisitor::<Decimal>(PhantomData))
+}
+
+/// Deserializes a date such as `"20230101"`, `"2023-01-01"`, `"2023.01.01"` or `"2023년 01월 01일"`.
+pub(crate) fn date<'de, D>(deserializer: D) -> Result<NaiveDate, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match optional_date(deserializer)? {
+        Reported::Parsed(date) => Ok(date),
+        Reported::Empty => Err(de::Error::custom("expected a date, got an empty value")),
+        Reported::Raw(text) => Err(de::Error::invalid_value(
+            de::Unexpected::Str(&text),
+            &NaiveDate::EXPECTING,
+        )),
+    }
+}
+
+/// Same as [`date`], but empty values and placeholders like `"-"` become [`Reported::Empty`],
+/// and text which isn't a date is kept as [`Reported::Raw`].
+pub(crate) fn optional_date<'de, D>(deserializer: D) -> Result<Reported<NaiveDate>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(FormattedVisitor::<NaiveDate>(PhantomData))
+}
+
+/// Deserializes a length of service such as `"10년 3개월"`, `"12.4년"` or `"12.4"` (years).
+///
+/// Empty values and placeholders like `"-"` become [`Reported::Empty`].
+pub(crate) fn tenure<'de, D>(deserializer: D) -> Result<Reported<Tenure>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(FormattedVisitor::<Tenure>(PhantomData))
+}
+
+/// Deserializes free text into a type which normalizes it, such as a code enum.
+///
+/// Empty values and placeholders like `"-"` become `None`.
+pub(crate) fn optional_text<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: From<String>,
+{
+    let value = <Option<String> as serde::Deserialize>::deserialize(deserializer)?;
+    Ok(value
+        .filter(|value| !EMPTY_PLACEHOLDERS.contains(&value.trim()))
+        .map(T::from))
+}
+
+/// Deserializes an identifier which is blank for some companies, such as the `stock_code` of an unlisted company.
+///
+/// Empty values and placeholders like `"-"` become `None`; other values must be valid.
+pub(crate) fn optional_identifier<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: TryFrom<String>,
+    T::Error: fmt::Display,
+{
+    let value = <Option<String> as serde::Deserialize>::deserialize(deserializer)?;
+    value
+        .map(|value| value.trim().to_string())
+        .filter(|value| !EMPTY_PLACEHOLDERS.contains(&value.as_str()))
+        .map(|value| T::try_from(value).map_err(de::Error::custom))
+        .transpose()
+}
+
+/// Finds the first KRW amount with a unit, such as `"1,234백만원"`, in free text.
+pub(crate) fn krw_in_text(text: &str) -> Option<i64> {
+    let is_number = |c: char| c.is_ascii_digit() || c == ',' || c == '.';
+
+    let mut rest = text;
+    while let Some(start) = rest.find(|c: char| c.is_ascii_digit()) {
+        let number = &rest[start..];
+        let end = number.find(|c| !is_number(c)).unwrap_or(number.len());
+        let (digits, after) = number.split_at(end);
+        let after = after.trim_start();
+
+        if let Some(unit) = KrwUnit::ALL
+            .iter()
+            .find(|unit| after.starts_with(&unit.to_string()))
+        {
+            if let Ok(Some(Krw(amount))) = Krw::parse_formatted(&format!("{digits}{unit}")) {
+                return Some(amount);
+            }
+        }
+        rest = after;
+    }
+    None
+}
+
+// region: Formatted values
+
+trait Formatted: Sized {
+    type Err;
+    const EXPECTING: &'static str;
+
+    fn parse(digits: &str) -> Result<Self, Self::Err>;
+    fn negate(self) -> Self;
+    fn from_i64(v: i64) -> Option<Self>;
+    fn from_u64(v: u64) -> Option<Self>;
+    fn from_f64(v: f64) -> Option<Self>;
+
+    fn parse_formatted(value: &str) -> Result<Option<Self>, Self::Err> {
+        let value = value.trim();
+        if EMPTY_PLACEHOLDERS.contains(&value) {
+            return Ok(None);
+        }
+
+        let (negative, value) = match value.strip_prefix('(').and_then(|v| v.strip_suffix(')')) {
+            Some(inner) => (true, inner),
+            None => (false, value),
+        };
+
+        let digits = value.trim_end_matches(['%', '원']).replace([',', ' '], "");
+        let parsed = Self::parse(&digits)?;
+        Ok(Some(if negative { parsed.negate() } else { parsed }))
+    }
+}
+
+impl Formatted for i64 {
+    type Err = std::num::ParseIntError;
+    const EXPECTING: &'static str = "an integer or a formatted integer string";
+
+    fn parse(digits: &str) -> Result<Self, Self::Err> {
+        digits.parse()
+    }
+
+    fn negate(self) -> Self {
+        -self
+    }
+
+    fn from_i64(v: i64) -> Option<Self> {
+        Some(v)
+    }
+
+    fn from_u64(v: u64) -> Option<Self> {
+        i64::try_from(v).ok()
+    }
+
+    fn from_f64(_v: f64) -> Option<Self> {
+        None
+    }
+}
+
+impl Formatted for Decimal {
+    type Err = rust_decimal::Error;
+    const EXPECTING: &'static str = "a decimal or a formatted decimal string";
+
+    fn parse(digits: &str) -> Result<Self, Self::Err> {
+        Decimal::from_str(digits)
+    }
+
+    fn negate(self) -> Self {
+        -self
+    }
+
+    fn from_i64(v: i64) -> Option<Self> {
+        Some(Decimal::from(v))
+    }
+
+    fn from_u64(v: u64) -> Option<Self> {
+        Some(Decimal::from(v))
+    }
+
+    fn from_f64(v: f64) -> Option<Self> {
+        <Decimal as FromPrimitive>::from_f64(v)
+    }
+}
+
+impl Formatted for Money {
+    type Err = rust_decimal::Error;
+    const EXPECTING: &'static str = "a number or a formatted amount string";
+
+    fn parse_formatted(value: &str) -> Result<Option<Self>, Self::Err> {
+        let value = value.trim();
+        let (value, unit) = KrwUnit::ALL
+            .iter()
+            .find_map(|unit| Some((value.strip_suffix(&unit.to_string())?, Some(*unit))))
+            .unwrap_or((value, None));
+
+        Ok(Decimal::parse_formatted(value)?.map(|value| Money { value, unit }))
+    }
+
+    fn parse(digits: &str) -> Result<Self, Self::Err> {
+        Decimal::parse(digits).map(|value| Money::new(value, None))
+    }
+
+    fn negate(self) -> Self {
+        Money::new(-self.value, self.unit)
+    }
+
+    fn from_i64(v: i64) -> Option<Self> {
+        Some(Money::new(v, None))
+    }
+
+    fn from_u64(v: u64) -> Option<Self> {
+        Some(Money::new(v, None))
+    }
+
+    fn from_f64(v: f64) -> Option<Self> {
+        <Decimal as Formatted>::from_f64(v).map(|value| Money::new(value, None))
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Money {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.deserialize_any(FormattedVisitor::<Money>(PhantomData))? {
+            Reported::Parsed(money) => Ok(money),
+            Reported::Empty => Err(de::Error::custom("expected an amount, got an empty value")),
+            Reported::Raw(text) => Err(de::Error::invalid_value(
+                de::Unexpected::Str(&text),
+                &Money::EXPECTING,
+            )),
+        }
+    }
+}
+
+struct Krw(i64);
+
+impl Formatted for Krw {
+    type Err = rust_decimal::Error;
+    const EXPECTING: &'static str = "an integer or a formatted KRW amount string";
+
+    fn parse_formatted(value: &str) -> Result<Option<Self>, Self::Err> {
+        let Some(money) = Money::parse_formatted(value)? else {
+            return Ok(None);
+        };
+        money
+            .krw(KrwUnit::Won)
+            .map(|amount| Some(Krw(amount)))
+            .ok_or(rust_decimal::Error::ExceedsMaximumPossibleValue)
+    }
+
+    fn parse(digits: &str) -> Result<Self, Self::Err> {
+        i64::from_str(digits)
+            .map(Krw)
+            .map_err(|_| rust_decimal::Error::ConversionTo("i64".to_string()))
+    }
+
+    fn negate(self) -> Self {
+        Krw(-self.0)
+    }
+
+    fn from_i64(v: i64) -> Option<Self> {
+        Some(Krw(v))
+    }
+
+    fn from_u64(v: u64) -> Option<Self> {
+        i64::try_from(v).ok().map(Krw)
+    }
+
+    fn from_f64(_v: f64) -> Option<Self> {
+        None
+    }
+}
+
+impl Formatted for NaiveDate {
+    type Err = chrono::ParseError;
+    const EXPECTING: &'static str = "a date string";
+
+    fn parse_formatted(value: &str) -> Result<Option<Self>, Self::Err> {
+        let value = value.trim();
+        if EMPTY_PLACEHOLDERS.contains(&value) {
+            return Ok(None);
+        }
+
+        // Filers don't always pad the month and day, e.g. `2023년 1월 2일`
+        let parts = value
+            .split(|c: char| !c.is_ascii_digit())
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>();
+        match parts.as_slice() {
+            [year, month, day] => {
+                NaiveDate::parse_from_str(&format!("{year}-{month}-{day}"), "%Y-%m-%d")
+            }
+            _ => NaiveDate::parse_from_str(&parts.concat(), "%Y%m%d"),
+        }
+        .map(Some)
+    }
+
+    fn parse(digits: &str) -> Result<Self, Self::Err> {
+        NaiveDate::parse_from_str(digits, "%Y%m%d")
+    }
+
+    fn negate(self) -> Self {
+        self
+    }
+
+    fn from_i64(_v: i64) -> Option<Self> {
+        None
+    }
+
+    fn from_u64(_v: u64) -> Option<Self> {
+        None
+    }
+
+    fn from_f64(_v: f64) -> Option<Self> {
+        None
+    }
+}
+
+impl Formatted for Tenure {
+    type Err = crate::error::ParseTenureError;
+    const EXPECTING: &'static str = "a tenure string";
+
+    fn parse_formatted(value: &str) -> Result<Option<Self>, Self::Err> {
+        let value = value.trim();
+        if EMPTY_PLACEHOLDERS.contains(&value) {
+            return Ok(None);
+        }
+        Self::parse(value).map(Some)
+    }
+
+    fn parse(digits: &str) -> Result<Self, Self::Err> {
+        Tenure::from_str(digits)
+    }
+
+    fn negate(self) -> Self {
+        self
+    }
+
+    fn from_i64(_v: i64) -> Option<Self> {
+        None
+    }
+
+    fn from_u64(_v: u64) -> Option<Self> {
+        None
+    }
+
+    fn from_f64(_v: f64) -> Option<Self> {
+        None
+    }
+}
+
+struct FormattedVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Formatted> Visitor<'de> for FormattedVisitor<T> {
+    type Value = Reported<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(T::EXPECTING)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        T::from_i64(v)
+            .map(Reported::Parsed)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Signed(v), &self))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        T::from_u64(v)
+            .map(Reported::Parsed)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        T::from_f64(v)
+            .map(Reported::Parsed)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Float(v), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(match T::parse_formatted(v) {
+            Ok(value) => value.into(),
+            Err(_) => Reported::Raw(v.trim().to_string()),
+        })
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(Reported::Empty)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(Reported::Empty)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, d: D) -> Result<Self::Value, D::Error> {
+        d.deserialize_any(self)
+    }
+}
+
+// endregion: Formatted values
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::identifiers::StockCode;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    struct Amount(#[serde(deserialize_with = "amount")] Reported<i64>);
+
+    #[derive(Debug, Deserialize)]
+    struct Krw(#[serde(deserialize_with = "krw")] Reported<i64>);
+
+    #[derive(Debug, Deserialize)]
+    struct MoneyValue(#[serde(deserialize_with = "money")] Reported<Money>);
+
+    #[derive(Debug, Deserialize)]
+    struct DecimalValue(#[serde(deserialize_with = "decimal")] Reported<Decimal>);
+
+    #[derive(Debug, Deserialize)]
+    struct Date(#[serde(deserialize_with = "date")] NaiveDate);
+
+    #[derive(Debug, Deserialize)]
+    struct OptionalDate(#[serde(deserialize_with = "optional_date")] Reported<NaiveDate>);
+
+    #[derive(Debug, Deserialize)]
+    struct TenureValue(#[serde(deserialize_with = "tenure")] Reported<Tenure>);
+
+    #[derive(Debug, Deserialize)]
+    struct OptionalText(#[serde(deserialize_with = "optional_text")] Option<String>);
+
+    #[derive(Debug, Deserialize)]
+    struct OptionalStockCode(#[serde(deserialize_with = "optional_identifier")] Option<StockCode>);
+
+    #[test]
+    fn amount_should_parse_formatted_strings() {
+        let cases = [
+            (r#""174,697,424,000,000""#, Some(174_697_424_000_000)),
+            (r#""-1,000""#, Some(-1_000)),
+            (r#""(1,000)""#, Some(-1_000)),
+            (r#""1000""#, Some(1_000)),
+            (r#""1,000원""#, Some(1_000)),
+            (r#""-""#, None),
+            (r#""–""#, None),
+            (r#"" - ""#, None),
+            (r#""""#, None),
+            ("1000", Some(1_000)),
+            ("null", None),
+        ];
+
+        for (json, expected) in cases {
+            let Amount(parsed) = serde_json::from_str(json).expect("Failed to deserialize");
+            assert_eq!(parsed, expected.into(), "json: {json}");
+        }
+    }
+
+    #[test]
+    fn amount_should_keep_unparsable_text() {
+        let Amount(parsed) =
+            serde_json::from_str(r#""해당사항 없음""#).expect("Failed to deserialize");
+        assert_eq!(parsed, Reported::Raw("해당사항 없음".to_string()));
+        assert_eq!(parsed.value(), None);
+        assert!(serde_json::from_str::<Amount>("1.5").is_err());
+    }
+
+    #[test]
+    fn unparsable_text_should_round_trip() {
+        let cases = [r#""미정""#, r#""해당사항 없음""#, r#""1,000원 (예정)""#];
+
+        for json in cases {
+            let Amount(parsed) = serde_json::from_str(json).expect("Failed to deserialize");
+            assert_eq!(
+                parsed.raw(),
+                serde_json::from_str::<String>(json).ok().as_deref()
+            );
+            assert_eq!(serde_json::to_string(&parsed).unwrap(), json);
+        }
+    }
+
+    #[test]
+    fn krw_should_normalize_units() {
+        let cases = [
+            (r#""1,234,000""#, Some(1_234_000)),
+            (r#""1,234,000원""#, Some(1_234_000)),
+            (r#""1,234 백만원""#, Some(1_234_000_000)),
+            (r#""1.5억원""#, Some(150_000_000)),
+            (r#""(2,000)""#, Some(-2_000)),
+            (r#""-""#, None),
+            ("1000", Some(1_000)),
+            ("null", None),
+        ];
+
+        for (json, expected) in cases {
+            let Krw(parsed) = serde_json::from_str(json).expect("Failed to deserialize");
+            assert_eq!(parsed, expected.into(), "json: {json}");
+        }
+    }
+
+    #[test]
+    fn money_should_keep_the_reported_unit() {
+        let cases = [
+            (r#""73,790""#, Some(Money::new(73_790, None))),
+            (
+                r#""8,850백만원""#,
+                Some(Money::new(8_850, Some(KrwUnit::Million))),
+            ),
+            (
+                r#""-1.5억원""#,
+                Some(Money::new(
+                    Decimal::new(-15, 1),
+                    Some(KrwUnit::HundredMillion),
+                )),
+            ),
+            (r#""-""#, None),
+            ("1000", Some(Money::new(1_000, None))),
+        ];
+
+        for (json, expected) in cases {
+            let MoneyValue(parsed) = serde_json::from_str(json).expect("Failed to deserialize");
+            assert_eq!(parsed, expected.into(), "json: {json}");
+
+            let serialized = serde_json::to_string(&parsed).unwrap();
+            let MoneyValue(reparsed) =
+                serde_json::from_str(&serialized).expect("Failed to deserialize");
+            assert_eq!(reparsed, parsed, "json: {serialized}");
+        }
+    }
+
+    #[test]
+    fn krw_in_text_should_find_first_amount_with_unit() {
+        let cases = [
+            (
+                "손해배상 청구금액: 1,234,567,890원 및 지연이자",
+                Some(1_234_567_890),
+            ),
+            ("2023년 청구금액 15억원", Some(1_500_000_000)),
+            ("소송가액 3,000 백만원", Some(3_000_000_000)),
+            ("특허권 침해금지 청구", None),
+        ];
+
+        for (text, expected) in cases {
+            assert_eq!(krw_in_text(text), expected, "text: {text}");
+        }
+    }
+
+    #[test]
+    fn decimal_should_parse_formatted_strings() {
+        let cases = [
+            (r#""12.345""#, Some(Decimal::new(12_345, 3))),
+            (r#""-0.5""#, Some(Decimal::new(-5, 1))),
+            (r#""1,234.5""#, Some(Decimal::new(12_345, 1))),
+            (r#""33.3%""#, Some(Decimal::new(333, 1))),
+            (r#""-""#, None),
+            ("1.5", Some(Decimal::new(15, 1))),
+            ("null", None),
+        ];
+
+        for (json, expected) in cases {
+            let DecimalValue(parsed) = serde_json::from_str(json).expect("Failed to deserialize");
+            assert_eq!(parsed, expected.into(), "json: {json}");
+        }
+    }
+
+    #[test]
+    fn optional_date_should_parse_formatted_strings() {
+        let date = NaiveDate::from_ymd_opt(2023, 1, 2);
+        let cases = [
+            (r#""20230102""#, date),
+            (r#""2023-01-02""#, date),
+            (r#""2023.01.02""#, date),
+            (r#""2023년 01월 02일""#, date),
+            (r#""2023년 1월 2일""#, date),
+            (r#""2023. 1. 2.""#, date),
+            (r#""-""#, None),
+            ("null", None),
+        ];
+
+        for (json, expected) in cases {
+            let OptionalDate(parsed) = serde_json::from_str(json).expect("Failed to deserialize");
+            assert_eq!(parsed, expected.into(), "json: {json}");
+        }
+
+        for json in [r#""2023-13-01""#, r#""미정""#] {
+            let OptionalDate(parsed) = serde_json::from_str(json).expect("Failed to deserialize");
+            assert_eq!(parsed.value(), None, "json: {json}");
+            assert!(parsed.raw().is_some(), "json: {json}");
+        }
+    }
+
+    #[test]
+    fn date_should_reject_empty_and_unparsable_values() {
+        let Date(parsed) = serde_json::from_str(r#""2023.01.02""#).expect("Failed to deserialize");
+        assert_eq!(Some(parsed), NaiveDate::from_ymd_opt(2023, 1, 2));
+
+        for json in [r#""-""#, r#""미정""#, "null"] {
+            assert!(serde_json::from_str::<Date>(json).is_err(), "json: {json}");
+        }
+    }
+
+    #[test]
+    fn tenure_should_parse_formatted_strings() {
+        let cases = [
+            (r#""10년 3개월""#, Some(Tenure::from_months(123))),
+            (r#""-""#, None),
+            ("null", None),
+        ];
+
+        for (json, expected) in cases {
+            let TenureValue(parsed) = serde_json::from_str(json).expect("Failed to deserialize");
+            assert_eq!(parsed, expected.into(), "json: {json}");
+        }
+
+        let TenureValue(parsed) = serde_json::from_str(r#""미정""#).expect("Failed to deserialize");
+        assert_eq!(parsed.raw(), Some("미정"));
+    }
+
+    #[test]
+    fn optional_text_should_treat_placeholders_as_none() {
+        let cases = [
+            (r#""적정""#, Some("적정")),
+            (r#""-""#, None),
+            (r#"" ""#, None),
+            ("null", None),
+        ];
+
+        for (json, expected) in cases {
+            let OptionalText(parsed) = serde_json::from_str(json).expect("Failed to deserialize");
+            assert_eq!(parsed.as_deref(), expected, "json: {json}");
+        }
+    }
+
+    #[test]
+    fn optional_identifier_should_skip_blank_values() {
+        let OptionalStockCode(parsed) =
+            serde_json::from_str(r#""005930""#).expect("Failed to deserialize");
+        assert_eq!(parsed, Some(StockCode::new("005930").unwrap()));
+
+        for json in [r#"" ""#, r#""""#, r#""-""#, "null"] {
+            let OptionalStockCode(parsed) =
+                serde_json::from_str(json).expect("Failed to deserialize");
+            assert_eq!(parsed, None, "json: {json}");
+        }
+
+        assert!(serde_json::from_str::<OptionalStockCode>(r#""invalid""#).is_err());
+    }
+}
@@ -0,0 +1,96 @@
+//! # 채무증권 발행실적
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS002&apiId=2020003>
+//!
+//! 정기보고서(사업, 분기, 반기보고서) 내에 채무증권 발행실적 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::{CorpCls, ReprtCode};
+use crate::endpoints::de::{decimal, money, optional_date};
+use crate::endpoints::identifiers::{BsnsYear, CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::money::Money;
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+impl OpenDartApi {
+    pub async fn get_det_scrits_isu_acmslt(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<DetScritsIsuAcmslt>>, OpenDartError> {
+        self.get(self.url("/api/detScritsIsuAcmslt.json"), args)
+            .await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    pub bsns_year: BsnsYear,
+    pub reprt_code: ReprtCode,
+);
+
+json_body!(DetScritsIsuAcmslt {
+    pub list: Vec<DetScritsIsuAcmsltElement>,
+});
+
+derive_common! {
+    DetScritsIsuAcmsltElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 발행회사
+        pub isu_cmpny: String,
+        /// 증권종류
+        pub scrits_knd_nm: String,
+        /// 발행방법
+        pub isu_mth_nm: String,
+        /// 발행일자
+        #[serde(default, deserialize_with = "optional_date")]
+        pub isu_de: Reported<NaiveDate>,
+        /// 권면(전자등록)총액
+        #[serde(default, deserialize_with = "money")]
+        pub facvalu_totamt: Reported<Money>,
+        /// 이자율
+        #[serde(default, deserialize_with = "decimal")]
+        pub intrt: Reported<Decimal>,
+        /// 평가등급(평가기관)
+        pub evl_grad_instt: String,
+        /// 만기일
+        pub mtd: String,
+        /// 상환여부
+        pub repy_at: String,
+        /// 주관회사
+        pub mngt_cmpny: String,
+        /// 결산기준일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub stlm_dt: Reported<NaiveDate>,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bsns_year(mock::bsns_year())
+            .reprt_code(mock::reprt_code())
+            .build()
+            .expect("Failed to build DetScritsIsuAcmsltRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bsns_year, mock::bsns_year());
+        assert_eq!(params.reprt_code, mock::reprt_code());
+    }
+}
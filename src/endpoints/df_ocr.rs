@@ -0,0 +1,96 @@
+//! # 부도발생
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS005&apiId=2020019>
+//!
+//! 주요사항보고서 내에 부도발생 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::CorpCls;
+use crate::endpoints::de::{krw, optional_date};
+use crate::endpoints::identifiers::{CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use crate::validate::fields::yyyymmdd;
+use chrono::NaiveDate;
+
+impl OpenDartApi {
+    pub async fn get_df_ocr(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<DfOcr>>, OpenDartError> {
+        self.get(self.url("/api/dfOcr.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub bgn_de: String,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub end_de: String,
+);
+
+json_body!(DfOcr {
+    pub list: Vec<DfOcrElement>,
+});
+
+derive_common! {
+    DfOcrElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 부도내용
+        pub df_cn: String,
+        /// 부도금액
+        #[serde(default, deserialize_with = "krw")]
+        pub df_amt: Reported<i64>,
+        /// 부도발생은행
+        pub df_bnk: String,
+        /// 최종부도(당좌거래정지)일자
+        #[serde(default, deserialize_with = "optional_date")]
+        pub dfd: Reported<NaiveDate>,
+        /// 부도사유 및 경위
+        pub df_rs: String,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de(mock::yyyymmdd())
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build DfOcrRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bgn_de, mock::yyyymmdd());
+        assert_eq!(params.end_de, "20231231");
+    }
+
+    #[tokio::test]
+    async fn invalid_params_should_fail_before_making_request() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de("invalid")
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build DfOcrRequestParams");
+
+        let api = OpenDartApi::default();
+        let response = api.get_df_ocr(params).await;
+        assert!(response.is_err());
+    }
+}
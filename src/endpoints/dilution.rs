@@ -0,0 +1,64 @@
+//! Dilution of existing shareholders by capital increase and mezzanine bond decisions.
+//!
+//! [`Dilution`] is implemented by the decision reports of 유상증자, 무상증자 and 유무상증자,
+//! and [`Mezzanine`] by those of 전환사채, 신주인수권부사채 and 교환사채.
+
+use rust_decimal::Decimal;
+
+/// A decision to issue new shares.
+pub trait Dilution {
+    /// 신주의 종류와 수 (보통주식, 기타주식)
+    fn new_shares(&self) -> (Option<i64>, Option<i64>);
+
+    /// 증자전 발행주식총수 (보통주식, 기타주식)
+    fn shares_before(&self) -> (Option<i64>, Option<i64>);
+
+    /// Number of new shares relative to the shares issued before the increase, in percent.
+    ///
+    /// Common and other shares are added up. Returns `None` when either side is unknown or zero.
+    fn dilution(&self) -> Option<Decimal> {
+        let new_shares = total(self.new_shares())?;
+        let shares_before = total(self.shares_before())?;
+        if shares_before == 0 {
+            return None;
+        }
+        Some(Decimal::from(new_shares) / Decimal::from(shares_before) * Decimal::ONE_HUNDRED)
+    }
+}
+
+/// A bond which can be converted into, exercised for or exchanged for shares.
+pub trait Mezzanine {
+    /// 사채의 권면총액 (원)
+    fn face_amount(&self) -> Option<i64>;
+
+    /// 전환가액, 행사가액 or 교환가액 (원/주)
+    fn exercise_price(&self) -> Option<i64>;
+
+    /// Number of shares to be issued or delivered at [`Mezzanine::exercise_price`]
+    fn issuable_shares(&self) -> Option<i64>;
+
+    /// [`Mezzanine::issuable_shares`] relative to the total number of shares, in percent
+    fn issuable_ratio(&self) -> Option<Decimal>;
+
+    /// 시가하락에 따른 최저 조정가액 (원/주), the floor of downward refixing
+    fn refixing_floor(&self) -> Option<i64>;
+
+    /// Number of shares issuable when the price is refixed down to [`Mezzanine::refixing_floor`].
+    ///
+    /// Falls back to [`Mezzanine::exercise_price`] when there are no refixing terms.
+    fn issuable_shares_at_floor(&self) -> Option<i64> {
+        let face_amount = self.face_amount()?;
+        let price = self
+            .refixing_floor()
+            .or_else(|| self.exercise_price())
+            .filter(|price| *price > 0)?;
+        Some(face_amount / price)
+    }
+}
+
+fn total((common, other): (Option<i64>, Option<i64>)) -> Option<i64> {
+    match (common, other) {
+        (None, None) => None,
+        (common, other) => Some(common.unwrap_or_default() + other.unwrap_or_default()),
+    }
+}
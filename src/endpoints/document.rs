@@ -0,0 +1,235 @@
+//! # 공시서류원본파일
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS001&apiId=2019003>
+//!
+//! 공시보고서 원본파일을 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::identifiers::RceptNo;
+use crate::endpoints::macros::{derive_common, params};
+use crate::error::{OpenDartError, UnexpectedZipContentError};
+use bytes::Bytes;
+use encoding_rs::{Encoding, EUC_KR, UTF_8};
+use std::io::{Cursor, Read};
+use zip::ZipArchive;
+
+impl OpenDartApi {
+    #[tracing::instrument(skip(self))]
+    pub async fn get_document(&self, args: Params) -> Result<Document, OpenDartError> {
+        let url = self.url("/api/document.xml");
+        let rcept_no = args.rcept_no.clone();
+        let bytes = self.get_zip(url, args).await?;
+        let cursor = Cursor::new(bytes);
+        let mut zip = ZipArchive::new(cursor)?;
+        Document::from_zip(rcept_no, &mut zip)
+    }
+}
+
+params!(
+    pub rcept_no: RceptNo,
+);
+
+derive_common! {
+    /// ## 공시서류원본파일
+    ///
+    /// The main document is the file named after the `rcept_no`,
+    /// and the other files in the archive are its attachments.
+    Document {
+        pub rcept_no: RceptNo,
+        pub main: DocumentFile,
+        pub attachments: Vec<DocumentFile>,
+    }
+}
+
+derive_common! {
+    /// A single file included in a [`Document`] archive.
+    DocumentFile {
+        pub name: String,
+        /// Decoded contents of the file. DART documents are often encoded as EUC-KR.
+        pub text: String,
+        /// Raw contents of the file as stored in the archive.
+        pub bytes: Vec<u8>,
+    }
+}
+
+impl Document {
+    #[tracing::instrument(skip(zip))]
+    fn from_zip(
+        rcept_no: RceptNo,
+        zip: &mut ZipArchive<Cursor<Bytes>>,
+    ) -> Result<Self, OpenDartError> {
+        let mut files = DocumentFile::from_zip(zip)?;
+
+        if files.is_empty() {
+            Err(UnexpectedZipContentError { files: Vec::new() })?;
+        }
+
+        // Fall back to the first file when no file is named after the `rcept_no`
+        let main_position = files
+            .iter()
+            .position(|file| rcept_no == file.stem())
+            .unwrap_or(0);
+        let main = files.remove(main_position);
+
+        Ok(Self {
+            rcept_no,
+            main,
+            attachments: files,
+        })
+    }
+
+    /// Iterates over the main document followed by its attachments.
+    pub fn files(&self) -> impl Iterator<Item = &DocumentFile> {
+        std::iter::once(&self.main).chain(self.attachments.iter())
+    }
+}
+
+impl DocumentFile {
+    /// Reads every file in the archive, sorted by file name.
+    #[tracing::instrument(skip(zip))]
+    pub(crate) fn from_zip(
+        zip: &mut ZipArchive<Cursor<Bytes>>,
+    ) -> Result<Vec<Self>, OpenDartError> {
+        let mut files = Vec::with_capacity(zip.len());
+        for i in 0..zip.len() {
+            let mut zip_file = zip.by_index(i)?;
+            if zip_file.is_dir() {
+                continue;
+            }
+
+            let mut bytes = Vec::with_capacity(zip_file.size() as usize);
+            zip_file
+                .read_to_end(&mut bytes)
+                .map_err(zip::result::ZipError::from)?;
+            files.push(DocumentFile {
+                name: zip_file.name().to_string(),
+                text: decode(&bytes),
+                bytes,
+            });
+        }
+        files.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(files)
+    }
+
+    /// File name without its extension
+    pub fn stem(&self) -> &str {
+        self.name
+            .rsplit_once('.')
+            .map_or(self.name.as_str(), |(stem, _)| stem)
+    }
+}
+
+/// Decodes the contents of a document file.
+///
+/// The encoding declared in the XML declaration is used if present.
+/// Otherwise, UTF-8 is tried first and EUC-KR is used as a fallback.
+fn decode(bytes: &[u8]) -> String {
+    if let Some((encoding, bom_length)) = Encoding::for_bom(bytes) {
+        let (text, _) = encoding.decode_without_bom_handling(&bytes[bom_length..]);
+        return text.into_owned();
+    }
+
+    let encoding = declared_encoding(bytes).unwrap_or_else(|| {
+        if std::str::from_utf8(bytes).is_ok() {
+            UTF_8
+        } else {
+            EUC_KR
+        }
+    });
+    let (text, _, had_errors) = encoding.decode(bytes);
+    if had_errors {
+        tracing::warn!(
+            encoding = encoding.name(),
+            "Failed to decode some characters"
+        );
+    }
+    text.into_owned()
+}
+
+/// Reads the `encoding` attribute of the XML declaration, e.g. `<?xml version="1.0" encoding="euc-kr"?>`.
+fn declared_encoding(bytes: &[u8]) -> Option<&'static Encoding> {
+    let head = &bytes[..bytes.len().min(256)];
+    let head = String::from_utf8_lossy(head);
+    let declaration = head.strip_prefix("<?xml")?.split("?>").next()?;
+    let (_, value) = declaration.split_once("encoding=")?;
+    let quote = value.chars().next()?;
+    let label = value[quote.len_utf8()..].split(quote).next()?;
+    Encoding::for_label(label.trim().as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+    use std::io::Write;
+    use zip::write::FileOptions;
+    use zip::ZipWriter;
+
+    const MAIN_CONTENT: &str = r#"<?xml version="1.0" encoding="euc-kr"?>
+<DOCUMENT><DOCUMENT-NAME ACODE="11011">사업보고서</DOCUMENT-NAME></DOCUMENT>"#;
+    const ATTACHMENT_CONTENT: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<DOCUMENT><DOCUMENT-NAME ACODE="00760">감사보고서</DOCUMENT-NAME></DOCUMENT>"#;
+
+    fn create_mock_zip_bytes(files: &[(&str, &[u8])]) -> Bytes {
+        let mut buf = Vec::new();
+        let mut writer = ZipWriter::new(Cursor::new(&mut buf));
+        for (name, content) in files {
+            writer
+                .start_file::<&str, ()>(name, FileOptions::default())
+                .unwrap();
+            writer.write_all(content).unwrap();
+        }
+        writer.finish().unwrap();
+
+        buf.into()
+    }
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .rcept_no(mock::rcept_no())
+            .build()
+            .expect("Failed to build DocumentRequestParams");
+
+        assert_eq!(params.rcept_no, mock::rcept_no());
+    }
+
+    #[test]
+    fn from_zip_should_accept_multiple_files() {
+        let rcept_no = mock::rcept_no();
+        let (main_bytes, _, _) = EUC_KR.encode(MAIN_CONTENT);
+        let main_name = format!("{rcept_no}.xml");
+        let attachment_name = format!("{rcept_no}_00760.xml");
+        let zip = create_mock_zip_bytes(&[
+            (&attachment_name, ATTACHMENT_CONTENT.as_bytes()),
+            (&main_name, &main_bytes),
+        ]);
+
+        let mut zip = ZipArchive::new(Cursor::new(zip)).unwrap();
+        let document = Document::from_zip(rcept_no.clone(), &mut zip).unwrap();
+
+        assert_eq!(document.rcept_no, rcept_no);
+        assert_eq!(document.main.name, main_name);
+        assert_eq!(document.main.text, MAIN_CONTENT);
+        assert_eq!(document.main.bytes, main_bytes.as_ref());
+        assert_eq!(document.attachments.len(), 1);
+        assert_eq!(document.attachments[0].name, attachment_name);
+        assert_eq!(document.attachments[0].text, ATTACHMENT_CONTENT);
+        assert_eq!(document.files().count(), 2);
+    }
+
+    #[test]
+    fn from_zip_should_error_when_there_are_no_files() {
+        let zip = create_mock_zip_bytes(&[]);
+
+        let mut zip = ZipArchive::new(Cursor::new(zip)).unwrap();
+        let document = Document::from_zip(mock::rcept_no(), &mut zip);
+        assert!(document.is_err());
+    }
+
+    #[test]
+    fn decode_should_fall_back_to_euc_kr_without_declaration() {
+        let (bytes, _, _) = EUC_KR.encode("<DOCUMENT>삼성전자</DOCUMENT>");
+        assert_eq!(decode(&bytes), "<DOCUMENT>삼성전자</DOCUMENT>");
+    }
+}
@@ -0,0 +1,87 @@
+//! # 이사ㆍ감사 전체의 보수현황(주주총회 승인금액)
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS002&apiId=2020014>
+//!
+//! 정기보고서(사업, 분기, 반기보고서) 내에 이사ㆍ감사 전체의 보수현황(주주총회 승인금액) 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::{CorpCls, ReprtCode};
+use crate::endpoints::de::{amount, optional_date};
+use crate::endpoints::identifiers::{BsnsYear, CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use chrono::NaiveDate;
+
+impl OpenDartApi {
+    pub async fn get_drctr_adt_all_mendng_sttus_gmtsck_confm_amount(
+        &self,
+        args: Params,
+    ) -> Result<
+        OpenDartResponse<ResponseBody<DrctrAdtAllMendngSttusGmtsckConfmAmount>>,
+        OpenDartError,
+    > {
+        self.get(
+            self.url("/api/drctrAdtAllMendngSttusGmtsckConfmAmount.json"),
+            args,
+        )
+        .await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    pub bsns_year: BsnsYear,
+    pub reprt_code: ReprtCode,
+);
+
+json_body!(DrctrAdtAllMendngSttusGmtsckConfmAmount {
+    pub list: Vec<DrctrAdtAllMendngSttusGmtsckConfmAmountElement>,
+});
+
+derive_common! {
+    DrctrAdtAllMendngSttusGmtsckConfmAmountElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 구분
+        pub se: String,
+        /// 인원수
+        #[serde(default, deserialize_with = "amount")]
+        pub nmpr: Reported<i64>,
+        /// 주주총회 승인금액
+        #[serde(default, deserialize_with = "amount")]
+        pub gmtsck_confm_amount: Reported<i64>,
+        /// 비고
+        pub rm: String,
+        /// 결산기준일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub stlm_dt: Reported<NaiveDate>,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bsns_year(mock::bsns_year())
+            .reprt_code(mock::reprt_code())
+            .build()
+            .expect("Failed to build DrctrAdtAllMendngSttusGmtsckConfmAmountRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bsns_year, mock::bsns_year());
+        assert_eq!(params.reprt_code, mock::reprt_code());
+    }
+}
@@ -0,0 +1,101 @@
+//! # 임원ㆍ주요주주 소유보고
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS004&apiId=2019022>
+//!
+//! 임원ㆍ주요주주특정증권등 소유상황보고서 내에 임원ㆍ주요주주 소유보고 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::de::{amount, date, decimal};
+use crate::endpoints::holding::{holding_history, HoldingChange, HoldingReport};
+use crate::endpoints::identifiers::{CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use std::collections::BTreeMap;
+
+impl OpenDartApi {
+    pub async fn get_elestock(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<Elestock>>, OpenDartError> {
+        self.get(self.url("/api/elestock.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+);
+
+json_body!(Elestock {
+    pub list: Vec<ElestockElement>,
+});
+
+impl Elestock {
+    /// Report history of each (`corp_code`, `repror`) pair, sorted by date.
+    pub fn history(&self) -> BTreeMap<(&str, &str), Vec<HoldingChange<'_, ElestockElement>>> {
+        holding_history(&self.list)
+    }
+}
+
+derive_common! {
+    ElestockElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 접수일자
+        #[serde(deserialize_with = "date")]
+        pub rcept_dt: NaiveDate,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 보고자
+        pub repror: String,
+        /// 발행 회사 관계 임원(등기여부)
+        pub isu_exctv_rgist_at: String,
+        /// 발행 회사 관계 임원 직위
+        pub isu_exctv_ofcps: String,
+        /// 발행 회사 관계 주요 주주
+        pub isu_main_shrholdr: String,
+        /// 특정 증권 등 소유 수
+        #[serde(default, deserialize_with = "amount")]
+        pub sp_stock_lmp_cnt: Reported<i64>,
+        /// 특정 증권 등 소유 증감 수
+        #[serde(default, deserialize_with = "amount")]
+        pub sp_stock_lmp_irds_cnt: Reported<i64>,
+        /// 특정 증권 등 소유 비율
+        #[serde(default, deserialize_with = "decimal")]
+        pub sp_stock_lmp_rate: Reported<Decimal>,
+        /// 특정 증권 등 소유 증감 비율
+        #[serde(default, deserialize_with = "decimal")]
+        pub sp_stock_lmp_irds_rate: Reported<Decimal>,
+    }
+}
+
+impl HoldingReport for ElestockElement {
+    fn corp_code(&self) -> &str {
+        self.corp_code.as_str()
+    }
+
+    fn reporter(&self) -> &str {
+        &self.repror
+    }
+
+    fn rcept_no(&self) -> &str {
+        self.rcept_no.as_str()
+    }
+
+    fn rcept_dt(&self) -> NaiveDate {
+        self.rcept_dt
+    }
+
+    fn shares(&self) -> Option<i64> {
+        self.sp_stock_lmp_cnt.value()
+    }
+
+    fn ratio(&self) -> Option<Decimal> {
+        self.sp_stock_lmp_rate.value()
+    }
+}
@@ -0,0 +1,242 @@
+//! # 직원 현황
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS002&apiId=2019011>
+//!
+//! 정기보고서(사업, 분기, 반기보고서) 내에 직원 현황을 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::{CorpCls, ReprtCode};
+use crate::endpoints::de::{amount, decimal, optional_date, tenure};
+use crate::endpoints::identifiers::{BsnsYear, CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::{OpenDartError, ParseTenureError};
+use chrono::NaiveDate;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use std::fmt;
+use std::str::FromStr;
+
+impl OpenDartApi {
+    pub async fn get_emp_sttus(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<EmpSttus>>, OpenDartError> {
+        self.get(self.url("/api/empSttus.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    pub bsns_year: BsnsYear,
+    pub reprt_code: ReprtCode,
+);
+
+json_body!(EmpSttus {
+    pub list: Vec<EmpSttusElement>,
+});
+
+impl EmpSttus {
+    /// Sum of `sm` over every row.
+    ///
+    /// Rows are reported per business segment and gender,
+    /// so this is the total headcount unless the report also contains subtotal rows.
+    pub fn headcount(&self) -> i64 {
+        self.list
+            .iter()
+            .filter_map(|element| element.sm.value())
+            .sum()
+    }
+}
+
+derive_common! {
+    EmpSttusElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 사업부문
+        pub fo_bbm: String,
+        /// 성별
+        pub sexdstn: String,
+        /// 개정 전 직원 수 정규직
+        #[serde(default, deserialize_with = "amount")]
+        pub reform_bfe_emp_co_rgllbr: Reported<i64>,
+        /// 개정 전 직원 수 계약직
+        #[serde(default, deserialize_with = "amount")]
+        pub reform_bfe_emp_co_cnttk: Reported<i64>,
+        /// 개정 전 직원 수 기타
+        #[serde(default, deserialize_with = "amount")]
+        pub reform_bfe_emp_co_etc: Reported<i64>,
+        /// 정규직 수
+        #[serde(default, deserialize_with = "amount")]
+        pub rgllbr_co: Reported<i64>,
+        /// 정규직 단시간 근로자 수
+        #[serde(default, deserialize_with = "amount")]
+        pub rgllbr_abacpt_labrr_co: Reported<i64>,
+        /// 계약직 수
+        #[serde(default, deserialize_with = "amount")]
+        pub cnttk_co: Reported<i64>,
+        /// 계약직 단시간 근로자 수
+        #[serde(default, deserialize_with = "amount")]
+        pub cnttk_abacpt_labrr_co: Reported<i64>,
+        /// 합계
+        #[serde(default, deserialize_with = "amount")]
+        pub sm: Reported<i64>,
+        /// 평균 근속 연수
+        #[serde(default, deserialize_with = "tenure")]
+        pub avrg_cnwk_sdytrn: Reported<Tenure>,
+        /// 연간 급여 총액
+        #[serde(default, deserialize_with = "decimal")]
+        pub fyer_salary_totamt: Reported<Decimal>,
+        /// 1인평균 급여 액
+        #[serde(default, deserialize_with = "decimal")]
+        pub jan_salary_am: Reported<Decimal>,
+        /// 비고
+        pub rm: String,
+        /// 결산기준일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub stlm_dt: Reported<NaiveDate>,
+    }
+}
+
+/// Length of service, such as `"10년 3개월"`.
+///
+/// Fractional years (e.g. `"12.4"`) are rounded to the nearest month.
+#[derive(std::fmt::Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Tenure {
+    months: u32,
+}
+
+impl Tenure {
+    pub fn from_months(months: u32) -> Self {
+        Self { months }
+    }
+
+    /// Total number of months
+    pub fn months(&self) -> u32 {
+        self.months
+    }
+
+    /// Number of whole years
+    pub fn years(&self) -> u32 {
+        self.months / 12
+    }
+
+    /// Tenure in fractional years
+    pub fn as_years(&self) -> Decimal {
+        Decimal::from(self.months) / Decimal::from(12)
+    }
+}
+
+impl fmt::Display for Tenure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (years, months) = (self.years(), self.months % 12);
+        match (years, months) {
+            (0, months) => write!(f, "{months}개월"),
+            (years, 0) => write!(f, "{years}년"),
+            (years, months) => write!(f, "{years}년 {months}개월"),
+        }
+    }
+}
+
+impl FromStr for Tenure {
+    type Err = ParseTenureError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseTenureError {
+            value: s.to_string(),
+        };
+
+        let value = s.chars().filter(|c| !c.is_whitespace()).collect::<String>();
+        if value.is_empty() {
+            return Err(error());
+        }
+
+        let (years, months) = match value.split_once('년') {
+            Some((years, months)) => (years, months),
+            None if value.ends_with("개월") => ("", value.as_str()),
+            None => (value.as_str(), ""),
+        };
+        let months = match months.strip_suffix("개월") {
+            Some(months) => months,
+            None if months.is_empty() => "",
+            None => return Err(error()),
+        };
+
+        let years = match years {
+            "" => Decimal::ZERO,
+            years => Decimal::from_str(years).map_err(|_| error())?,
+        };
+        let months = match months {
+            "" => 0,
+            months => months.parse::<u32>().map_err(|_| error())?,
+        };
+        let years_in_months = (years * Decimal::from(12))
+            .round()
+            .to_u32()
+            .ok_or_else(error)?;
+
+        Ok(Self::from_months(years_in_months + months))
+    }
+}
+
+impl serde::Serialize for Tenure {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn tenure_should_parse_from_str() {
+        let cases = [
+            ("10년 3개월", 123),
+            ("10년3개월", 123),
+            ("10년", 120),
+            ("3개월", 3),
+            ("12.5", 150),
+            ("12.5년", 150),
+            ("0.1", 1),
+        ];
+
+        for (value, months) in cases {
+            let tenure = Tenure::from_str(value).expect("Failed to parse tenure");
+            assert_eq!(tenure.months(), months, "value: {value}");
+        }
+
+        assert!(Tenure::from_str("").is_err());
+        assert!(Tenure::from_str("10년 3일").is_err());
+        assert!(Tenure::from_str("-1").is_err());
+    }
+
+    #[test]
+    fn tenure_should_display_as_years_and_months() {
+        assert_eq!(Tenure::from_months(123).to_string(), "10년 3개월");
+        assert_eq!(Tenure::from_months(120).to_string(), "10년");
+        assert_eq!(Tenure::from_months(3).to_string(), "3개월");
+    }
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bsns_year(mock::bsns_year())
+            .reprt_code(mock::reprt_code())
+            .build()
+            .expect("Failed to build EmpSttusRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bsns_year, mock::bsns_year());
+        assert_eq!(params.reprt_code, mock::reprt_code());
+    }
+}
@@ -0,0 +1,148 @@
+//! # 기업어음증권 미상환 잔액
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS002&apiId=2020004>
+//!
+//! 정기보고서(사업, 분기, 반기보고서) 내에 기업어음증권 미상환 잔액 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::{CorpCls, ReprtCode};
+use crate::endpoints::de::{money, optional_date};
+use crate::endpoints::identifiers::{BsnsYear, CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::maturity::{Maturity, MaturityBalance, MaturityBucket};
+use crate::endpoints::money::Money;
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use chrono::NaiveDate;
+
+impl OpenDartApi {
+    pub async fn get_entrprs_bil_scrits_nrdmp_blce(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<EntrprsBilScritsNrdmpBlce>>, OpenDartError> {
+        self.get(self.url("/api/entrprsBilScritsNrdmpBlce.json"), args)
+            .await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    pub bsns_year: BsnsYear,
+    pub reprt_code: ReprtCode,
+);
+
+json_body!(EntrprsBilScritsNrdmpBlce {
+    pub list: Vec<EntrprsBilScritsNrdmpBlceElement>,
+});
+
+derive_common! {
+    EntrprsBilScritsNrdmpBlceElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 잔여만기 (e.g. 공모, 사모, 합계)
+        pub remndr_exprtn1: String,
+        /// 잔여만기 (e.g. 미상환 잔액)
+        pub remndr_exprtn2: String,
+        /// 10일 이하
+        #[serde(default, deserialize_with = "money")]
+        pub de10_below: Reported<Money>,
+        /// 10일 초과 30일 이하
+        #[serde(default, deserialize_with = "money")]
+        pub de10_excess_de30_below: Reported<Money>,
+        /// 30일 초과 90일 이하
+        #[serde(default, deserialize_with = "money")]
+        pub de30_excess_de90_below: Reported<Money>,
+        /// 90일 초과 180일 이하
+        #[serde(default, deserialize_with = "money")]
+        pub de90_excess_de180_below: Reported<Money>,
+        /// 180일 초과 1년 이하
+        #[serde(default, deserialize_with = "money")]
+        pub de180_excess_yy1_below: Reported<Money>,
+        /// 1년 초과 2년 이하
+        #[serde(default, deserialize_with = "money")]
+        pub yy1_excess_yy2_below: Reported<Money>,
+        /// 2년 초과 3년 이하
+        #[serde(default, deserialize_with = "money")]
+        pub yy2_excess_yy3_below: Reported<Money>,
+        /// 3년 초과
+        #[serde(default, deserialize_with = "money")]
+        pub yy3_excess: Reported<Money>,
+        /// 합계
+        #[serde(default, deserialize_with = "money")]
+        pub sm: Reported<Money>,
+        /// 결산기준일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub stlm_dt: Reported<NaiveDate>,
+    }
+}
+
+impl MaturityBalance for EntrprsBilScritsNrdmpBlceElement {
+    fn buckets(&self) -> Vec<MaturityBucket> {
+        use Maturity::{Days, Years};
+        vec![
+            MaturityBucket::new(None, Some(Days(10)), self.de10_below.value()),
+            MaturityBucket::new(
+                Some(Days(10)),
+                Some(Days(30)),
+                self.de10_excess_de30_below.value(),
+            ),
+            MaturityBucket::new(
+                Some(Days(30)),
+                Some(Days(90)),
+                self.de30_excess_de90_below.value(),
+            ),
+            MaturityBucket::new(
+                Some(Days(90)),
+                Some(Days(180)),
+                self.de90_excess_de180_below.value(),
+            ),
+            MaturityBucket::new(
+                Some(Days(180)),
+                Some(Years(1)),
+                self.de180_excess_yy1_below.value(),
+            ),
+            MaturityBucket::new(
+                Some(Years(1)),
+                Some(Years(2)),
+                self.yy1_excess_yy2_below.value(),
+            ),
+            MaturityBucket::new(
+                Some(Years(2)),
+                Some(Years(3)),
+                self.yy2_excess_yy3_below.value(),
+            ),
+            MaturityBucket::new(Some(Years(3)), None, self.yy3_excess.value()),
+        ]
+    }
+
+    fn sum(&self) -> Option<Money> {
+        self.sm.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bsns_year(mock::bsns_year())
+            .reprt_code(mock::reprt_code())
+            .build()
+            .expect("Failed to build EntrprsBilScritsNrdmpBlceRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bsns_year, mock::bsns_year());
+        assert_eq!(params.reprt_code, mock::reprt_code());
+    }
+}
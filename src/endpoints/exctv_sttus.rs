@@ -0,0 +1,155 @@
+//! # 임원 현황
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS002&apiId=2019010>
+//!
+//! 정기보고서(사업, 분기, 반기보고서) 내에 임원 현황을 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::{CorpCls, ReprtCode};
+use crate::endpoints::de::optional_date;
+use crate::endpoints::emp_sttus::Tenure;
+use crate::endpoints::identifiers::{BsnsYear, CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use chrono::NaiveDate;
+
+impl OpenDartApi {
+    pub async fn get_exctv_sttus(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<ExctvSttus>>, OpenDartError> {
+        self.get(self.url("/api/exctvSttus.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    pub bsns_year: BsnsYear,
+    pub reprt_code: ReprtCode,
+);
+
+json_body!(ExctvSttus {
+    pub list: Vec<ExctvSttusElement>,
+});
+
+impl ExctvSttus {
+    /// 등기임원
+    pub fn registered(&self) -> impl Iterator<Item = &ExctvSttusElement> {
+        self.list.iter().filter(|element| element.is_registered())
+    }
+}
+
+derive_common! {
+    ExctvSttusElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 성명
+        pub nm: String,
+        /// 성별
+        pub sexdstn: String,
+        /// 출생 년월
+        pub birth_ym: String,
+        /// 직위
+        pub ofcps: String,
+        /// 등기 임원 여부 (e.g. 사내이사, 사외이사, 미등기)
+        pub rgist_exctv_at: String,
+        /// 상근 여부
+        pub fte_at: String,
+        /// 담당 업무
+        pub chrg_job: String,
+        /// 주요 경력
+        pub main_career: String,
+        /// 최대 주주 관계
+        pub mxmm_shrholdr_relate: String,
+        /// 재직 기간
+        ///
+        /// Free-form text; see [`ExctvSttusElement::tenure`] for a parsed value.
+        pub hffc_pd: String,
+        /// 임기 만료 일
+        pub tenure_end_on: String,
+        /// 결산기준일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub stlm_dt: Reported<NaiveDate>,
+    }
+}
+
+impl ExctvSttusElement {
+    /// Parses `hffc_pd`, returning `None` when it isn't formatted like `"10년 3개월"`.
+    pub fn tenure(&self) -> Option<Tenure> {
+        self.hffc_pd.parse().ok()
+    }
+
+    /// Whether the executive is registered (등기임원).
+    pub fn is_registered(&self) -> bool {
+        !self.rgist_exctv_at.contains("미등기")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+    use serde_json::json;
+
+    fn element(nm: &str, rgist_exctv_at: &str, hffc_pd: &str) -> serde_json::Value {
+        json!({
+            "rcept_no": mock::rcept_no(),
+            "corp_cls": "Y",
+            "corp_code": mock::corp_code(),
+            "corp_name": mock::corp_name(),
+            "nm": nm,
+            "sexdstn": "남",
+            "birth_ym": "1968년 01월",
+            "ofcps": "대표이사",
+            "rgist_exctv_at": rgist_exctv_at,
+            "fte_at": "상근",
+            "chrg_job": "경영총괄",
+            "main_career": "-",
+            "mxmm_shrholdr_relate": "-",
+            "hffc_pd": hffc_pd,
+            "tenure_end_on": "2025.03.15",
+            "stlm_dt": "2023-12-31",
+        })
+    }
+
+    #[test]
+    fn registered_should_skip_unregistered_executives() {
+        let body: ExctvSttus = serde_json::from_value(json!({
+            "status": "000",
+            "message": "정상",
+            "list": [
+                element("가나다", "사내이사", "10년 3개월"),
+                element("라마바", "미등기", "-"),
+            ],
+        }))
+        .expect("Failed to deserialize ExctvSttus");
+
+        let registered = body.registered().collect::<Vec<_>>();
+        assert_eq!(registered.len(), 1);
+        assert_eq!(registered[0].nm, "가나다");
+        assert!(registered[0].tenure().is_some());
+        assert!(body.list[1].tenure().is_none());
+    }
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bsns_year(mock::bsns_year())
+            .reprt_code(mock::reprt_code())
+            .build()
+            .expect("Failed to build ExctvSttusRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bsns_year, mock::bsns_year());
+        assert_eq!(params.reprt_code, mock::reprt_code());
+    }
+}
@@ -0,0 +1,174 @@
+//! # 주식의포괄적교환ㆍ이전
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS006&apiId=2020061>
+//!
+//! 증권신고서 내에 주식의포괄적교환ㆍ이전 요약 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::CorpCls;
+use crate::endpoints::de::optional_date;
+use crate::endpoints::identifiers::{CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::registration::{IssuedSecurity, Party};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use crate::validate::fields::yyyymmdd;
+use chrono::NaiveDate;
+
+impl OpenDartApi {
+    pub async fn get_extr_rs(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<ExtrRs>>, OpenDartError> {
+        self.get(self.url("/api/extrRs.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub bgn_de: String,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub end_de: String,
+);
+
+json_body!(ExtrRs {
+    pub group: Vec<ExtrRsGroup>,
+});
+
+impl ExtrRs {
+    /// Rows of the 일반사항 section
+    pub fn general(&self) -> &[ExtrRsGeneral] {
+        self.group
+            .iter()
+            .find_map(|group| match group {
+                ExtrRsGroup::General(list) => Some(list.as_slice()),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    /// Rows of the 발행증권 section
+    pub fn securities(&self) -> &[IssuedSecurity] {
+        self.group
+            .iter()
+            .find_map(|group| match group {
+                ExtrRsGroup::Securities(list) => Some(list.as_slice()),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    /// Rows of the 당사회사에관한사항 section
+    pub fn parties(&self) -> &[Party] {
+        self.group
+            .iter()
+            .find_map(|group| match group {
+                ExtrRsGroup::Parties(list) => Some(list.as_slice()),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// A section of the summary, identified by its `title`
+#[derive(
+    std::fmt::Debug,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    // serde
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[serde(tag = "title", content = "list")]
+pub enum ExtrRsGroup {
+    /// 일반사항
+    #[serde(rename = "일반사항")]
+    General(Vec<ExtrRsGeneral>),
+    /// 발행증권
+    #[serde(rename = "발행증권")]
+    Securities(Vec<IssuedSecurity>),
+    /// 당사회사에관한사항
+    #[serde(rename = "당사회사에관한사항")]
+    Parties(Vec<Party>),
+}
+
+derive_common! {
+    ExtrRsGeneral {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 형태
+        pub stn: String,
+        /// 이사회 결의일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub bddd: Reported<NaiveDate>,
+        /// 계약일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub ctrd: Reported<NaiveDate>,
+        /// 주주총회를 위한 주주확정일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub gmtsck_sd: Reported<NaiveDate>,
+        /// 주주총회 예정일자
+        #[serde(default, deserialize_with = "optional_date")]
+        pub gmtsck_prd: Reported<NaiveDate>,
+        /// 주식매수청구권 행사 기간 및 가격(시작일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub aprskh_pd_bgd: Reported<NaiveDate>,
+        /// 주식매수청구권 행사 기간 및 가격(종료일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub aprskh_pd_edd: Reported<NaiveDate>,
+        /// 주식매수청구권 행사 기간 및 가격(주식매수예정가격)
+        pub aprskh_prc: String,
+        /// 주식교환ㆍ이전일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub extrd: Reported<NaiveDate>,
+        /// 신주의 상장예정일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub nstk_lstprd: Reported<NaiveDate>,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de(mock::yyyymmdd())
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build ExtrRsRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bgn_de, mock::yyyymmdd());
+        assert_eq!(params.end_de, "20231231");
+    }
+
+    #[tokio::test]
+    async fn invalid_params_should_fail_before_making_request() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de("invalid")
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build ExtrRsRequestParams");
+
+        let api = OpenDartApi::default();
+        let response = api.get_extr_rs(params).await;
+        assert!(response.is_err());
+    }
+}
@@ -0,0 +1,129 @@
+//! ## 다중회사 주요 재무지표 개발가이드
+//! [link](https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS003&apiId=2022002)
+//! 상장법인(유가증권, 코스닥) 및 주요 비상장법인(사업보고서 제출대상 & IFRS 적용)이 제출한 정기보고서 내에 XBRL재무제표의 주요 재무지표를 제공합니다. (대상법인 복수조회 가능)
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::{IdxClCode, ReprtCode};
+use crate::endpoints::de::{decimal, optional_identifier};
+use crate::endpoints::identifiers::{BsnsYear, CorpCode, CorpCodes, StockCode};
+use crate::endpoints::macros::{derive_common, json_body, multi_corp_code, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::validate::fields::corp_codes;
+use crate::OpenDartError;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use std::collections::BTreeMap;
+
+impl OpenDartApi {
+    pub async fn get_fnltt_cmpny_indx(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<FnlttCmpnyIndx>>, OpenDartError> {
+        self.get(self.url("/api/fnlttCmpnyIndx.json"), args).await
+    }
+}
+
+params!(
+    /// ※ 복수조회 가능 (쉼표로 구분, 최대 100건)
+    #[validate(custom(function = "corp_codes"))]
+    pub corp_code: CorpCodes,
+    pub bsns_year: BsnsYear,
+    pub reprt_code: ReprtCode,
+    pub idx_cl_code: IdxClCode,
+);
+
+json_body!(FnlttCmpnyIndx {
+    pub list: Vec<FnlttCmpnyIndxElement>,
+});
+
+multi_corp_code!(FnlttCmpnyIndx, FnlttCmpnyIndxElement);
+
+impl FnlttCmpnyIndx {
+    /// Values of an index by its `idx_code` (e.g. `M211550` for ROE), keyed by `corp_code`.
+    pub fn values(&self, idx_code: &str) -> BTreeMap<&str, Option<Decimal>> {
+        self.list
+            .iter()
+            .filter(|element| element.idx_code == idx_code)
+            .map(|element| (element.corp_code.as_str(), element.idx_val.value()))
+            .collect()
+    }
+}
+
+derive_common! {
+    FnlttCmpnyIndxElement {
+        pub reprt_code: ReprtCode,
+        pub bsns_year: BsnsYear,
+        pub corp_code: CorpCode,
+        #[serde(default, deserialize_with = "optional_identifier")]
+        pub stock_code: Option<StockCode>,
+        pub stlm_dt: NaiveDate,
+        pub idx_cl_code: IdxClCode,
+        pub idx_cl_nm: String,
+        pub idx_code: String,
+        pub idx_nm: String,
+        #[serde(default, deserialize_with = "decimal")]
+        pub idx_val: Reported<Decimal>,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+    use serde_json::json;
+
+    fn element(corp_code: &str, idx_code: &str, idx_val: &str) -> serde_json::Value {
+        json!({
+            "reprt_code": mock::reprt_code(),
+            "bsns_year": mock::bsns_year(),
+            "corp_code": corp_code,
+            "stock_code": "",
+            "stlm_dt": "2023-12-31",
+            "idx_cl_code": "M210000",
+            "idx_cl_nm": "수익성지표",
+            "idx_code": idx_code,
+            "idx_nm": "ROE",
+            "idx_val": idx_val,
+        })
+    }
+
+    #[test]
+    fn values_should_be_keyed_by_corp_code() {
+        let body: FnlttCmpnyIndx = serde_json::from_value(json!({
+            "status": "000",
+            "message": "정상",
+            "list": [
+                element("00164779", "M211550", "12.5"),
+                element("00126380", "M211550", "-"),
+                element("00164779", "M211300", "30.1"),
+            ],
+        }))
+        .expect("Failed to deserialize FnlttCmpnyIndx");
+
+        let companies = body.by_corp_code();
+        assert_eq!(companies[0].0, "00164779");
+        assert_eq!(companies[0].1.len(), 2);
+
+        let roe = body.values("M211550");
+        assert_eq!(roe.len(), 2);
+        assert_eq!(roe["00164779"], Some(Decimal::new(125, 1)));
+        assert_eq!(roe["00126380"], None);
+    }
+
+    #[tokio::test]
+    async fn invalid_params_should_fail_before_making_request() {
+        let params = ParamsBuilder::default()
+            .corp_codes((0..=100).map(|i| CorpCode::new(format!("{i:08}")).unwrap()))
+            .bsns_year(mock::bsns_year())
+            .reprt_code(mock::reprt_code())
+            .idx_cl_code(IdxClCode::Stability)
+            .build()
+            .expect("Failed to build FnlttCmpnyIndxRequestParams");
+
+        let api = OpenDartApi::default();
+        let response = api.get_fnltt_cmpny_indx(params).await;
+        assert!(response.is_err());
+    }
+}
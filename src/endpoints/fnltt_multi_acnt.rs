@@ -2,14 +2,152 @@
 //! [link](https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS003&apiId=2019017)
 //! 상장법인(유가증권, 코스닥) 및 주요 비상장법인(사업보고서 제출대상 & IFRS 적용)이 제출한 정기보고서 내에 XBRL재무제표의 주요계정과목(재무상태표, 손익계산서)을 제공합니다. (대상법인 복수조회 복수조회 가능)
 
-use crate::endpoints::macros::params;
-use crate::validate::fields::{bsns_year, corp_code, reprt_code};
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::{FsDiv, ReprtCode};
+use crate::endpoints::de::{amount, optional_identifier};
+use crate::endpoints::identifiers::{BsnsYear, CorpCode, CorpCodes, RceptNo, StockCode};
+use crate::endpoints::macros::{derive_common, json_body, multi_corp_code, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::validate::fields::corp_codes;
+use crate::OpenDartError;
+
+impl OpenDartApi {
+    pub async fn get_fnltt_multi_acnt(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<FnlttMultiAcnt>>, OpenDartError> {
+        self.get(self.url("/api/fnlttMultiAcnt.json"), args).await
+    }
+}
 
 params!(
-    #[validate(custom(function = "corp_code"))]
-    pub corp_code: String,
-    #[validate(custom(function = "bsns_year"))]
-    pub bsns_year: String,
-    #[validate(custom(function = "reprt_code"))]
-    pub reprt_code: String,
+    /// ※ 복수조회 가능 (쉼표로 구분, 최대 100건)
+    #[validate(custom(function = "corp_codes"))]
+    pub corp_code: CorpCodes,
+    pub bsns_year: BsnsYear,
+    pub reprt_code: ReprtCode,
 );
+
+json_body!(FnlttMultiAcnt {
+    pub list: Vec<FnlttMultiAcntElement>,
+});
+
+multi_corp_code!(FnlttMultiAcnt, FnlttMultiAcntElement);
+
+derive_common! {
+    FnlttMultiAcntElement {
+        pub rcept_no: RceptNo,
+        pub bsns_year: BsnsYear,
+        pub corp_code: CorpCode,
+        #[serde(default, deserialize_with = "optional_identifier")]
+        pub stock_code: Option<StockCode>,
+        pub reprt_code: ReprtCode,
+        pub account_nm: String,
+        pub fs_div: FsDiv,
+        pub fs_nm: String,
+        pub sj_div: String,
+        pub sj_nm: String,
+        pub thstrm_nm: String,
+        pub thstrm_dt: String,
+        #[serde(default, deserialize_with = "amount")]
+        pub thstrm_amount: Reported<i64>,
+        #[serde(default, deserialize_with = "amount")]
+        pub thstrm_add_amount: Reported<i64>,
+        pub frmtrm_nm: Option<String>,
+        pub frmtrm_dt: Option<String>,
+        #[serde(default, deserialize_with = "amount")]
+        pub frmtrm_amount: Reported<i64>,
+        #[serde(default, deserialize_with = "amount")]
+        pub frmtrm_add_amount: Reported<i64>,
+        pub bfefrmtrm_nm: Option<String>,
+        pub bfefrmtrm_dt: Option<String>,
+        #[serde(default, deserialize_with = "amount")]
+        pub bfefrmtrm_amount: Reported<i64>,
+        pub ord: String,
+        pub currency: String,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::identifiers::CorpCode;
+    use crate::test_utils::mock;
+    use serde_json::json;
+
+    fn element(corp_code: &str, account_nm: &str) -> serde_json::Value {
+        json!({
+            "rcept_no": "20240312000736",
+            "bsns_year": mock::bsns_year(),
+            "corp_code": corp_code,
+            "stock_code": "",
+            "reprt_code": mock::reprt_code(),
+            "account_nm": account_nm,
+            "fs_div": mock::fs_div(),
+            "fs_nm": "연결재무제표",
+            "sj_div": "BS",
+            "sj_nm": "재무상태표",
+            "thstrm_nm": "제 55 기",
+            "thstrm_dt": "2023.12.31 현재",
+            "thstrm_amount": "1,000",
+            "ord": "1",
+            "currency": "KRW",
+        })
+    }
+
+    #[test]
+    fn by_corp_code_should_keep_the_order_of_companies() {
+        let body: FnlttMultiAcnt = serde_json::from_value(json!({
+            "status": "000",
+            "message": "정상",
+            "list": [
+                element("00164779", "유동자산"),
+                element("00126380", "유동자산"),
+                element("00164779", "자산총계"),
+            ],
+        }))
+        .expect("Failed to deserialize FnlttMultiAcnt");
+
+        let companies = body.by_corp_code();
+        assert_eq!(companies.len(), 2);
+        assert_eq!(companies[0].0, "00164779");
+        assert_eq!(companies[1].0, "00126380");
+        let accounts = companies[0]
+            .1
+            .iter()
+            .map(|element| element.account_nm.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(accounts, ["유동자산", "자산총계"]);
+    }
+
+    #[test]
+    fn params_builder_works_with_multiple_corp_codes() {
+        let params = ParamsBuilder::default()
+            .corp_codes([
+                CorpCode::new("00126380").unwrap(),
+                CorpCode::new("00164779").unwrap(),
+            ])
+            .bsns_year(mock::bsns_year())
+            .reprt_code(mock::reprt_code())
+            .build()
+            .expect("Failed to build FnlttMultiAcntRequestParams");
+
+        assert_eq!(params.corp_code.to_string(), "00126380,00164779");
+    }
+
+    #[tokio::test]
+    async fn invalid_params_should_fail_before_making_request() {
+        let params = ParamsBuilder::default()
+            .corp_codes((0..=100).map(|i| CorpCode::new(format!("{i:08}")).unwrap()))
+            .bsns_year(mock::bsns_year())
+            .reprt_code(mock::reprt_code())
+            .build()
+            .expect("Failed to build FnlttMultiAcntRequestParams");
+
+        let api = OpenDartApi::default();
+        let response = api.get_fnltt_multi_acnt(params).await;
+        assert!(response.is_err());
+    }
+}
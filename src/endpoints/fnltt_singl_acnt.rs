@@ -0,0 +1,63 @@
+//! ## 단일회사 주요계정 개발가이드
+//! [link](https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS003&apiId=2019016)
+//! 상장법인(유가증권, 코스닥) 및 주요 비상장법인(사업보고서 제출대상 & IFRS 적용)이 제출한 정기보고서 내에 XBRL재무제표의 주요계정과목(재무상태표, 손익계산서)을 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::{FsDiv, ReprtCode};
+use crate::endpoints::fnltt_multi_acnt::FnlttMultiAcntElement;
+use crate::endpoints::identifiers::{BsnsYear, CorpCode};
+use crate::endpoints::macros::{json_body, params};
+use crate::endpoints::OpenDartResponse;
+use crate::OpenDartError;
+
+impl OpenDartApi {
+    pub async fn get_fnltt_singl_acnt(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<FnlttSinglAcnt>>, OpenDartError> {
+        self.get(self.url("/api/fnlttSinglAcnt.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    pub bsns_year: BsnsYear,
+    pub reprt_code: ReprtCode,
+);
+
+json_body!(FnlttSinglAcnt {
+    pub list: Vec<FnlttSinglAcntElement>,
+});
+
+impl FnlttSinglAcnt {
+    /// Rows of the given `fs_div`, i.e. either the consolidated or the separate statements.
+    pub fn by_fs_div(&self, fs_div: FsDiv) -> impl Iterator<Item = &FnlttSinglAcntElement> {
+        self.list
+            .iter()
+            .filter(move |element| element.fs_div == fs_div)
+    }
+}
+
+/// The rows have the same columns as the multi-company endpoint.
+pub type FnlttSinglAcntElement = FnlttMultiAcntElement;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bsns_year(mock::bsns_year())
+            .reprt_code(mock::reprt_code())
+            .build()
+            .expect("Failed to build FnlttSinglAcntRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bsns_year, mock::bsns_year());
+        assert_eq!(params.reprt_code, mock::reprt_code());
+    }
+}
@@ -4,9 +4,10 @@
 
 use crate::client::OpenDartApi;
 use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::{FsDiv, ReprtCode};
+use crate::endpoints::identifiers::{BsnsYear, CorpCode, RceptNo};
 use crate::endpoints::macros::{derive_common, json_body, params};
 use crate::endpoints::OpenDartResponse;
-use crate::validate::fields::{bsns_year, corp_code, fs_div, reprt_code};
 use crate::OpenDartError;
 
 impl OpenDartApi {
@@ -20,14 +21,10 @@ impl OpenDartApi {
 }
 
 params!(
-    #[validate(custom(function = "corp_code"))]
-    pub corp_code: String,
-    #[validate(custom(function = "bsns_year"))]
-    pub bsns_year: String,
-    #[validate(custom(function = "reprt_code"))]
-    pub reprt_code: String,
-    #[validate(custom(function = "fs_div"))]
-    pub fs_div: String,
+    pub corp_code: CorpCode,
+    pub bsns_year: BsnsYear,
+    pub reprt_code: ReprtCode,
+    pub fs_div: FsDiv,
 );
 
 json_body!(FnlttSinglAcntAll {
@@ -36,10 +33,10 @@ json_body!(FnlttSinglAcntAll {
 
 derive_common! {
     FnlttSinglAcntAllElement {
-        rcept_no: String,
-        reprt_code:String,
-        bsns_year:String,
-        corp_code:String,
+        rcept_no: RceptNo,
+        reprt_code: ReprtCode,
+        bsns_year: BsnsYear,
+        corp_code: CorpCode,
         sj_div:String,
         sj_nm:String,
         account_id:String,
@@ -68,13 +65,19 @@ mod tests {
 
     #[tokio::test]
     async fn invalid_params_should_fail_before_making_request() {
-        let params = ParamsBuilder::default()
-            .corp_code("invalid")
-            .bsns_year("invalid")
-            .reprt_code("invalid")
-            .fs_div("invalid")
+        assert!(CorpCode::new("invalid").is_err());
+        assert!(BsnsYear::new("invalid").is_err());
+        assert!("invalid".parse::<ReprtCode>().is_err());
+        assert!("invalid".parse::<FsDiv>().is_err());
+
+        let mut params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bsns_year(mock::bsns_year())
+            .reprt_code(mock::reprt_code())
+            .fs_div(mock::fs_div())
             .build()
             .expect("Failed to build FnlttSinglAcntAllRequestParams");
+        params.crtfc_key = "invalid".to_string();
 
         let api = OpenDartApi::default();
         let response = api.get_fnltt_singl_acnt_all(params).await;
@@ -0,0 +1,89 @@
+//! ## 단일회사 주요 재무지표 개발가이드
+//! [link](https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS003&apiId=2022001)
+//! 상장법인(유가증권, 코스닥) 및 주요 비상장법인(사업보고서 제출대상 & IFRS 적용)이 제출한 정기보고서 내에 XBRL재무제표의 주요 재무지표를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::{IdxClCode, ReprtCode};
+use crate::endpoints::de::{decimal, optional_identifier};
+use crate::endpoints::identifiers::{BsnsYear, CorpCode, StockCode};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::OpenDartError;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+impl OpenDartApi {
+    pub async fn get_fnltt_singl_indx(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<FnlttSinglIndx>>, OpenDartError> {
+        self.get(self.url("/api/fnlttSinglIndx.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    pub bsns_year: BsnsYear,
+    pub reprt_code: ReprtCode,
+    pub idx_cl_code: IdxClCode,
+);
+
+json_body!(FnlttSinglIndx {
+    pub list: Vec<FnlttSinglIndxElement>,
+});
+
+impl FnlttSinglIndx {
+    /// Finds the value of an index by its `idx_code` (e.g. `M211550` for ROE).
+    pub fn value(&self, idx_code: &str) -> Option<Decimal> {
+        self.list
+            .iter()
+            .find(|element| element.idx_code == idx_code)
+            .and_then(|element| element.idx_val.value())
+    }
+}
+
+derive_common! {
+    FnlttSinglIndxElement {
+        pub reprt_code: ReprtCode,
+        pub bsns_year: BsnsYear,
+        pub corp_code: CorpCode,
+        #[serde(default, deserialize_with = "optional_identifier")]
+        pub stock_code: Option<StockCode>,
+        pub stlm_dt: NaiveDate,
+        pub idx_cl_code: IdxClCode,
+        pub idx_cl_nm: String,
+        pub idx_code: String,
+        pub idx_nm: String,
+        #[serde(default, deserialize_with = "decimal")]
+        pub idx_val: Reported<Decimal>,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn idx_cl_code_should_serialize_as_code() {
+        let serialized = serde_json::to_string(&IdxClCode::Growth).unwrap();
+        assert_eq!(serialized, r#""M230000""#);
+        assert_eq!(IdxClCode::Growth.to_string(), "M230000");
+    }
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bsns_year(mock::bsns_year())
+            .reprt_code(mock::reprt_code())
+            .idx_cl_code(IdxClCode::Profitability)
+            .build()
+            .expect("Failed to build FnlttSinglIndxRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.idx_cl_code, IdxClCode::Profitability);
+    }
+}
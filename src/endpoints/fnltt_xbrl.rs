@@ -0,0 +1,153 @@
+//! ## 재무제표 원본파일(XBRL) 개발가이드
+//! [link](https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS003&apiId=2019019)
+//! 상장법인(유가증권, 코스닥) 및 주요 비상장법인(사업보고서 제출대상 & IFRS 적용)이 제출한 정기보고서 내의 XBRL재무제표의 원본파일(XBRL)을 제공합니다.
+
+mod instance;
+
+use crate::client::OpenDartApi;
+use crate::endpoints::codes::ReprtCode;
+use crate::endpoints::document::DocumentFile;
+use crate::endpoints::identifiers::RceptNo;
+use crate::endpoints::macros::{derive_common, params};
+use crate::error::{OpenDartError, UnexpectedZipContentError};
+use bytes::Bytes;
+use std::io::Cursor;
+use zip::ZipArchive;
+
+pub use instance::{Context, Decimals, Dimension, Fact, Period, QName, Unit, XbrlInstance};
+
+impl OpenDartApi {
+    #[tracing::instrument(skip(self))]
+    pub async fn get_fnltt_xbrl(&self, args: Params) -> Result<FnlttXbrl, OpenDartError> {
+        let url = self.url("/api/fnlttXbrl.xml");
+        let rcept_no = args.rcept_no.clone();
+        let bytes = self.get_zip(url, args).await?;
+        let cursor = Cursor::new(bytes);
+        let mut zip = ZipArchive::new(cursor)?;
+        FnlttXbrl::from_zip(rcept_no, &mut zip)
+    }
+}
+
+params!(
+    pub rcept_no: RceptNo,
+    pub reprt_code: ReprtCode,
+);
+
+derive_common! {
+    /// ## 재무제표 원본파일(XBRL)
+    ///
+    /// The archive contains the instance document (`.xbrl`)
+    /// along with the entity's extension taxonomy (`.xsd`) and linkbases.
+    FnlttXbrl {
+        pub rcept_no: RceptNo,
+        pub files: Vec<DocumentFile>,
+        pub instance: XbrlInstance,
+    }
+}
+
+impl FnlttXbrl {
+    #[tracing::instrument(skip(zip))]
+    fn from_zip(
+        rcept_no: RceptNo,
+        zip: &mut ZipArchive<Cursor<Bytes>>,
+    ) -> Result<Self, OpenDartError> {
+        let files = DocumentFile::from_zip(zip)?;
+
+        let instance_file = files
+            .iter()
+            .find(|file| file.name.ends_with(".xbrl"))
+            .ok_or_else(|| UnexpectedZipContentError {
+                files: files.iter().map(|file| file.name.clone()).collect(),
+            })?;
+        let instance = XbrlInstance::from_reader(instance_file.bytes.as_slice())?;
+
+        Ok(Self {
+            rcept_no,
+            files,
+            instance,
+        })
+    }
+
+    /// The instance document file
+    pub fn instance_file(&self) -> Option<&DocumentFile> {
+        self.files.iter().find(|file| file.name.ends_with(".xbrl"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+    use std::io::Write;
+    use zip::write::FileOptions;
+    use zip::ZipWriter;
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .rcept_no(mock::rcept_no())
+            .reprt_code(mock::reprt_code())
+            .build()
+            .expect("Failed to build FnlttXbrlRequestParams");
+
+        assert_eq!(params.rcept_no, mock::rcept_no());
+        assert_eq!(params.reprt_code, mock::reprt_code());
+    }
+
+    #[test]
+    fn from_zip_should_error_without_instance_document() {
+        let mut buf = Vec::new();
+        let mut writer = ZipWriter::new(Cursor::new(&mut buf));
+        writer
+            .start_file::<&str, ()>("entity00126380_2023-12-31.xsd", FileOptions::default())
+            .unwrap();
+        writer.write_all(b"<xsd:schema/>").unwrap();
+        writer.finish().unwrap();
+
+        let mut zip = ZipArchive::new(Cursor::new(Bytes::from(buf))).unwrap();
+        assert!(FnlttXbrl::from_zip(mock::rcept_no(), &mut zip).is_err());
+    }
+
+    #[test]
+    fn from_zip_should_parse_instance_document() {
+        let instance = r#"<?xml version="1.0" encoding="utf-8"?>
+<xbrli:xbrl xmlns:xbrli="http://www.xbrl.org/2003/instance"
+    xmlns:ifrs-full="http://xbrl.ifrs.org/taxonomy/2021-03-24/ifrs-full"
+    xmlns:iso4217="http://www.xbrl.org/2003/iso4217">
+  <xbrli:context id="CFY2023eFY">
+    <xbrli:entity><xbrli:identifier scheme="http://dart.fss.or.kr">00126380</xbrli:identifier></xbrli:entity>
+    <xbrli:period><xbrli:instant>2023-12-31</xbrli:instant></xbrli:period>
+  </xbrli:context>
+  <xbrli:unit id="KRW"><xbrli:measure>iso4217:KRW</xbrli:measure></xbrli:unit>
+  <ifrs-full:Assets contextRef="CFY2023eFY" unitRef="KRW" decimals="-6">455905980000000</ifrs-full:Assets>
+</xbrli:xbrl>"#;
+
+        let mut buf = Vec::new();
+        let mut writer = ZipWriter::new(Cursor::new(&mut buf));
+        for (name, bytes) in [
+            ("entity00126380_2023-12-31.xsd", b"<xsd:schema/>".as_slice()),
+            ("entity00126380_2023-12-31.xbrl", instance.as_bytes()),
+        ] {
+            writer
+                .start_file::<&str, ()>(name, FileOptions::default())
+                .unwrap();
+            writer.write_all(bytes).unwrap();
+        }
+        writer.finish().unwrap();
+
+        let mut zip = ZipArchive::new(Cursor::new(Bytes::from(buf))).unwrap();
+        let xbrl = FnlttXbrl::from_zip(mock::rcept_no(), &mut zip).unwrap();
+
+        assert_eq!(xbrl.files.len(), 2);
+        assert_eq!(
+            xbrl.instance_file().map(|file| file.name.as_str()),
+            Some("entity00126380_2023-12-31.xbrl")
+        );
+        let assets = xbrl
+            .instance
+            .facts_by_account_id("ifrs-full_Assets")
+            .next()
+            .unwrap();
+        assert_eq!(assets.as_i64(), Some(455_905_980_000_000));
+    }
+}
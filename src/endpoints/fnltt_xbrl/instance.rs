@@ -0,0 +1,665 @@
+//! Parser for XBRL instance documents.
+//!
+//! Only the parts of the [XBRL 2.1 specification](https://www.xbrl.org/Specification/XBRL-2.1/REC-2003-12-31/XBRL-2.1-REC-2003-12-31+corrected-errata-2013-02-20.html)
+//! which are needed to read the facts of a DART filing are supported:
+//! contexts (entity, period and explicit/typed dimensions), units and item facts.
+//! Tuples and footnotes are skipped.
+
+use crate::endpoints::macros::derive_common;
+use crate::error::OpenDartError;
+use chrono::NaiveDate;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::name::{Namespace, QName as XmlQName, ResolveResult};
+use quick_xml::NsReader;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::io::BufRead;
+use validator::ValidationError;
+
+const XBRLI: &[u8] = b"http://www.xbrl.org/2003/instance";
+const XBRLDI: &[u8] = b"http://xbrl.org/2006/xbrldi";
+const LINK: &[u8] = b"http://www.xbrl.org/2003/linkbase";
+const XSI: &[u8] = b"http://www.w3.org/2001/XMLSchema-instance";
+
+derive_common! {
+    /// A parsed XBRL instance document.
+    XbrlInstance {
+        pub contexts: BTreeMap<String, Context>,
+        pub units: BTreeMap<String, Unit>,
+        pub facts: Vec<Fact>,
+    }
+}
+
+derive_common! {
+    /// A single reported value, with its context and unit resolved.
+    Fact {
+        pub id: Option<String>,
+        pub concept: QName,
+        pub context_ref: String,
+        pub period: Period,
+        pub dimensions: Vec<Dimension>,
+        pub unit_ref: Option<String>,
+        pub unit: Option<Unit>,
+        pub decimals: Option<Decimals>,
+        /// `true` when the fact was reported with `xsi:nil="true"`
+        pub nil: bool,
+        pub value: String,
+    }
+}
+
+derive_common! {
+    /// A qualified XML name, resolved against the namespace declarations in scope.
+    QName {
+        pub namespace: String,
+        pub prefix: String,
+        pub local_name: String,
+    }
+}
+
+derive_common! {
+    Context {
+        pub id: String,
+        pub entity: String,
+        pub period: Period,
+        pub dimensions: Vec<Dimension>,
+    }
+}
+
+derive_common! {
+    /// A dimension member of a context's segment or scenario.
+    ///
+    /// For explicit members, `member` is the member's QName as written (e.g. `ifrs-full:ConsolidatedMember`).
+    /// For typed members, it is the text content of the typed member.
+    Dimension {
+        pub dimension: QName,
+        pub member: String,
+        pub typed: bool,
+    }
+}
+
+derive_common! {
+    /// A unit of measure, e.g. `iso4217:KRW` or `iso4217:KRW / xbrli:shares`.
+    Unit {
+        pub id: String,
+        pub numerators: Vec<String>,
+        pub denominators: Vec<String>,
+    }
+}
+
+#[derive(
+    std::fmt::Debug,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    // derive_more
+    derive_more::Display,
+    // serde
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub enum Period {
+    #[display("{_0}")]
+    Instant(NaiveDate),
+    #[display("{start}~{end}")]
+    Duration { start: NaiveDate, end: NaiveDate },
+    #[display("forever")]
+    Forever,
+}
+
+/// The `decimals` attribute of a numeric fact.
+#[derive(
+    std::fmt::Debug,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    // derive_more
+    derive_more::Display,
+    // serde
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub enum Decimals {
+    #[display("INF")]
+    Infinite,
+    #[display("{_0}")]
+    Value(i32),
+}
+
+impl std::str::FromStr for Decimals {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "INF" => Ok(Decimals::Infinite),
+            s => s.parse().map(Decimals::Value),
+        }
+    }
+}
+
+impl QName {
+    /// The concept in the `{prefix}_{local_name}` form used as `account_id` by OpenDart,
+    /// e.g. `ifrs-full_Revenue`.
+    pub fn account_id(&self) -> String {
+        format!("{}_{}", self.prefix, self.local_name)
+    }
+}
+
+impl Unit {
+    /// Whether the unit is a single measure without a denominator, e.g. `iso4217:KRW`
+    pub fn is_simple(&self) -> bool {
+        self.numerators.len() == 1 && self.denominators.is_empty()
+    }
+}
+
+impl Fact {
+    /// Parses the value as an integer, which is how most monetary facts are reported.
+    pub fn as_i64(&self) -> Option<i64> {
+        self.value.trim().parse().ok()
+    }
+
+    /// Whether the context of the fact has no dimensions,
+    /// i.e. the value is reported for the entity as a whole.
+    pub fn is_default_context(&self) -> bool {
+        self.dimensions.is_empty()
+    }
+}
+
+impl XbrlInstance {
+    /// Facts reported for the given concept, e.g. `ifrs-full_Revenue`.
+    pub fn facts_by_account_id<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> impl Iterator<Item = &'a Fact> {
+        self.facts
+            .iter()
+            .filter(move |fact| fact.concept.account_id() == account_id)
+    }
+
+    #[tracing::instrument(skip(reader))]
+    pub(crate) fn from_reader<R: BufRead>(reader: R) -> Result<Self, OpenDartError> {
+        let mut xml_reader = NsReader::from_reader(reader);
+        xml_reader.config_mut().trim_text(true);
+
+        let mut contexts = BTreeMap::new();
+        let mut units = BTreeMap::new();
+        let mut raw_facts = Vec::new();
+
+        let mut buf = Vec::new();
+        let mut state = State::default();
+
+        loop {
+            let (ns, event) = xml_reader.read_resolved_event_into(&mut buf)?;
+            let namespace = match ns {
+                ResolveResult::Bound(Namespace(ns)) => ns.to_vec(),
+                _ => Vec::new(),
+            };
+
+            match event {
+                Event::Start(ref e) => {
+                    state.text.clear();
+                    let local_name = e.local_name();
+                    match (namespace.as_slice(), local_name.as_ref()) {
+                        (XBRLI, b"context") => {
+                            state.context = Some(ContextBuilder {
+                                id: required_attribute(e, b"id")?,
+                                ..Default::default()
+                            });
+                        }
+                        (XBRLI, b"unit") => {
+                            state.unit = Some(Unit {
+                                id: required_attribute(e, b"id")?,
+                                numerators: Vec::new(),
+                                denominators: Vec::new(),
+                            });
+                        }
+                        (XBRLI, b"unitDenominator") => state.in_denominator = true,
+                        (XBRLDI, b"explicitMember") | (XBRLDI, b"typedMember") => {
+                            let dimension = required_attribute(e, b"dimension")?;
+                            state.dimension = Some(resolve_qname(&xml_reader, &dimension)?);
+                        }
+                        (XBRLI, _) | (LINK, _) => {}
+                        // Elements nested in a typed member
+                        _ if state.dimension.is_some() => {}
+                        _ => {
+                            if let Some(context_ref) = attribute(e, b"contextRef")? {
+                                state.fact = Some(RawFact {
+                                    id: attribute(e, b"id")?,
+                                    concept: element_qname(e, &namespace)?,
+                                    context_ref,
+                                    unit_ref: attribute(e, b"unitRef")?,
+                                    decimals: decimals(e)?,
+                                    nil: is_nil(&xml_reader, e)?,
+                                    value: String::new(),
+                                });
+                            }
+                        }
+                    }
+                }
+                Event::Empty(ref e) => {
+                    let local_name = e.local_name();
+                    match (namespace.as_slice(), local_name.as_ref()) {
+                        (XBRLI, b"forever") => {
+                            if let Some(context) = state.context.as_mut() {
+                                context.forever = true;
+                            }
+                        }
+                        (XBRLI, _) | (LINK, _) | (XBRLDI, _) => {}
+                        _ => {
+                            if let Some(context_ref) = attribute(e, b"contextRef")? {
+                                raw_facts.push(RawFact {
+                                    id: attribute(e, b"id")?,
+                                    concept: element_qname(e, &namespace)?,
+                                    context_ref,
+                                    unit_ref: attribute(e, b"unitRef")?,
+                                    decimals: decimals(e)?,
+                                    nil: is_nil(&xml_reader, e)?,
+                                    value: String::new(),
+                                });
+                            }
+                        }
+                    }
+                }
+                Event::Text(ref e) => {
+                    state.text.push_str(&e.unescape()?);
+                }
+                Event::CData(ref e) => {
+                    state.text.push_str(&String::from_utf8_lossy(e));
+                }
+                Event::End(ref e) => {
+                    let local_name = e.local_name();
+                    let text = std::mem::take(&mut state.text);
+                    match (namespace.as_slice(), local_name.as_ref()) {
+                        (XBRLI, b"context") => {
+                            if let Some(context) = state.context.take() {
+                                let context = context.build()?;
+                                contexts.insert(context.id.clone(), context);
+                            }
+                        }
+                        (XBRLI, b"identifier") => {
+                            if let Some(context) = state.context.as_mut() {
+                                context.entity = text.trim().to_string();
+                            }
+                        }
+                        (XBRLI, b"instant") => {
+                            if let Some(context) = state.context.as_mut() {
+                                context.instant = Some(parse_date(&text)?);
+                            }
+                        }
+                        (XBRLI, b"startDate") => {
+                            if let Some(context) = state.context.as_mut() {
+                                context.start = Some(parse_date(&text)?);
+                            }
+                        }
+                        (XBRLI, b"endDate") => {
+                            if let Some(context) = state.context.as_mut() {
+                                context.end = Some(parse_date(&text)?);
+                            }
+                        }
+                        (XBRLI, b"unit") => {
+                            if let Some(unit) = state.unit.take() {
+                                units.insert(unit.id.clone(), unit);
+                            }
+                        }
+                        (XBRLI, b"unitDenominator") => state.in_denominator = false,
+                        (XBRLI, b"measure") => {
+                            if let Some(unit) = state.unit.as_mut() {
+                                let measure = text.trim().to_string();
+                                if state.in_denominator {
+                                    unit.denominators.push(measure);
+                                } else {
+                                    unit.numerators.push(measure);
+                                }
+                            }
+                        }
+                        (XBRLDI, b"explicitMember") | (XBRLDI, b"typedMember") => {
+                            let typed = local_name.as_ref() == b"typedMember";
+                            let member = if typed {
+                                std::mem::take(&mut state.typed_member_text)
+                            } else {
+                                text.trim().to_string()
+                            };
+                            if let (Some(dimension), Some(context)) =
+                                (state.dimension.take(), state.context.as_mut())
+                            {
+                                context.dimensions.push(Dimension {
+                                    dimension,
+                                    member,
+                                    typed,
+                                });
+                            }
+                        }
+                        (XBRLI, _) | (LINK, _) => {}
+                        _ if state.dimension.is_some() => {
+                            state.typed_member_text.push_str(text.trim());
+                        }
+                        _ => {
+                            if let Some(mut fact) = state.fact.take() {
+                                fact.value = text;
+                                raw_facts.push(fact);
+                            }
+                        }
+                    }
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+            buf.clear();
+        }
+
+        let facts = raw_facts
+            .into_iter()
+            .map(|fact| fact.resolve(&contexts, &units))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            contexts,
+            units,
+            facts,
+        })
+    }
+}
+
+// region: Parser state
+
+#[derive(Default)]
+struct State {
+    context: Option<ContextBuilder>,
+    unit: Option<Unit>,
+    in_denominator: bool,
+    dimension: Option<QName>,
+    typed_member_text: String,
+    fact: Option<RawFact>,
+    text: String,
+}
+
+#[derive(Default)]
+struct ContextBuilder {
+    id: String,
+    entity: String,
+    instant: Option<NaiveDate>,
+    start: Option<NaiveDate>,
+    end: Option<NaiveDate>,
+    forever: bool,
+    dimensions: Vec<Dimension>,
+}
+
+impl ContextBuilder {
+    fn build(self) -> Result<Context, OpenDartError> {
+        let period = match (self.instant, self.start, self.end, self.forever) {
+            (Some(instant), None, None, false) => Period::Instant(instant),
+            (None, Some(start), Some(end), false) => Period::Duration { start, end },
+            (None, None, None, true) => Period::Forever,
+            _ => {
+                let mut err = ValidationError::new("invalid_period");
+                err.add_param(Cow::from("context"), &self.id);
+                Err(err)?
+            }
+        };
+
+        Ok(Context {
+            id: self.id,
+            entity: self.entity,
+            period,
+            dimensions: self.dimensions,
+        })
+    }
+}
+
+struct RawFact {
+    id: Option<String>,
+    concept: QName,
+    context_ref: String,
+    unit_ref: Option<String>,
+    decimals: Option<Decimals>,
+    nil: bool,
+    value: String,
+}
+
+impl RawFact {
+    fn resolve(
+        self,
+        contexts: &BTreeMap<String, Context>,
+        units: &BTreeMap<String, Unit>,
+    ) -> Result<Fact, OpenDartError> {
+        let context = contexts.get(&self.context_ref).ok_or_else(|| {
+            let mut err = ValidationError::new("undefined_context");
+            err.add_param(Cow::from("context_ref"), &self.context_ref);
+            err
+        })?;
+
+        let unit = match &self.unit_ref {
+            Some(unit_ref) => Some(units.get(unit_ref).cloned().ok_or_else(|| {
+                let mut err = ValidationError::new("undefined_unit");
+                err.add_param(Cow::from("unit_ref"), unit_ref);
+                err
+            })?),
+            None => None,
+        };
+
+        Ok(Fact {
+            id: self.id,
+            concept: self.concept,
+            context_ref: self.context_ref,
+            period: context.period.clone(),
+            dimensions: context.dimensions.clone(),
+            unit_ref: self.unit_ref,
+            unit,
+            decimals: self.decimals,
+            nil: self.nil,
+            value: self.value,
+        })
+    }
+}
+
+// endregion: Parser state
+
+// region: Helpers
+
+fn attribute(e: &BytesStart, name: &[u8]) -> Result<Option<String>, OpenDartError> {
+    match e.try_get_attribute(name).map_err(quick_xml::Error::from)? {
+        Some(attribute) => Ok(Some(attribute.unescape_value()?.into_owned())),
+        None => Ok(None),
+    }
+}
+
+fn required_attribute(e: &BytesStart, name: &[u8]) -> Result<String, OpenDartError> {
+    attribute(e, name)?.ok_or_else(|| {
+        let mut err = ValidationError::new("missing_attribute");
+        err.add_param(
+            Cow::from("element"),
+            &String::from_utf8_lossy(e.name().as_ref()),
+        );
+        err.add_param(Cow::from("attribute"), &String::from_utf8_lossy(name));
+        err.into()
+    })
+}
+
+fn decimals(e: &BytesStart) -> Result<Option<Decimals>, OpenDartError> {
+    attribute(e, b"decimals")?
+        .map(|value| {
+            value.parse().map_err(|_| {
+                let mut err = ValidationError::new("invalid_decimals");
+                err.add_param(Cow::from("value"), &value);
+                err.into()
+            })
+        })
+        .transpose()
+}
+
+fn is_nil<R>(reader: &NsReader<R>, e: &BytesStart) -> Result<bool, OpenDartError> {
+    for attribute in e.attributes() {
+        let attribute = attribute.map_err(quick_xml::Error::from)?;
+        if let (ResolveResult::Bound(Namespace(XSI)), local_name) =
+            reader.resolve_attribute(attribute.key)
+        {
+            if local_name.as_ref() == b"nil" {
+                return Ok(attribute.unescape_value()?.trim() == "true");
+            }
+        }
+    }
+    Ok(false)
+}
+
+fn element_qname(e: &BytesStart, namespace: &[u8]) -> Result<QName, OpenDartError> {
+    Ok(QName {
+        namespace: std::str::from_utf8(namespace)?.to_string(),
+        prefix: e
+            .name()
+            .prefix()
+            .map(|prefix| String::from_utf8_lossy(prefix.as_ref()).into_owned())
+            .unwrap_or_default(),
+        local_name: String::from_utf8_lossy(e.local_name().as_ref()).into_owned(),
+    })
+}
+
+/// Resolves a QName written in an attribute value or text, e.g. `ifrs-full:ComponentsOfEquityAxis`.
+fn resolve_qname<R>(reader: &NsReader<R>, qname: &str) -> Result<QName, OpenDartError> {
+    let qname = qname.trim();
+    let (ns, local_name) = reader.resolve_element(XmlQName(qname.as_bytes()));
+    let namespace = match ns {
+        ResolveResult::Bound(Namespace(ns)) => std::str::from_utf8(ns)?.to_string(),
+        _ => String::new(),
+    };
+
+    Ok(QName {
+        namespace,
+        prefix: qname
+            .split_once(':')
+            .map(|(prefix, _)| prefix.to_string())
+            .unwrap_or_default(),
+        local_name: std::str::from_utf8(local_name.as_ref())?.to_string(),
+    })
+}
+
+fn parse_date(value: &str) -> Result<NaiveDate, OpenDartError> {
+    // Dates may be written as `xs:dateTime`, in which case only the date part is used
+    let value = value.trim();
+    let date = value.get(..10).unwrap_or(value);
+    Ok(NaiveDate::parse_from_str(date, "%Y-%m-%d")?)
+}
+
+// endregion: Helpers
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INSTANCE: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<xbrli:xbrl xmlns:xbrli="http://www.xbrl.org/2003/instance"
+    xmlns:link="http://www.xbrl.org/2003/linkbase"
+    xmlns:xlink="http://www.w3.org/1999/xlink"
+    xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance"
+    xmlns:xbrldi="http://xbrl.org/2006/xbrldi"
+    xmlns:iso4217="http://www.xbrl.org/2003/iso4217"
+    xmlns:ifrs-full="http://xbrl.ifrs.org/taxonomy/2021-03-24/ifrs-full"
+    xmlns:dart="http://dart.fss.or.kr/xbrl/dte/2023-06-30">
+  <link:schemaRef xlink:type="simple" xlink:href="entity00126380_2023-12-31.xsd"/>
+  <xbrli:context id="CFY2023dFY">
+    <xbrli:entity>
+      <xbrli:identifier scheme="http://dart.fss.or.kr">00126380</xbrli:identifier>
+      <xbrli:segment>
+        <xbrldi:explicitMember dimension="ifrs-full:ConsolidatedAndSeparateFinancialStatementsAxis">ifrs-full:ConsolidatedMember</xbrldi:explicitMember>
+      </xbrli:segment>
+    </xbrli:entity>
+    <xbrli:period>
+      <xbrli:startDate>2023-01-01</xbrli:startDate>
+      <xbrli:endDate>2023-12-31</xbrli:endDate>
+    </xbrli:period>
+  </xbrli:context>
+  <xbrli:context id="CFY2023eFY">
+    <xbrli:entity>
+      <xbrli:identifier scheme="http://dart.fss.or.kr">00126380</xbrli:identifier>
+    </xbrli:entity>
+    <xbrli:period>
+      <xbrli:instant>2023-12-31</xbrli:instant>
+    </xbrli:period>
+  </xbrli:context>
+  <xbrli:unit id="KRW">
+    <xbrli:measure>iso4217:KRW</xbrli:measure>
+  </xbrli:unit>
+  <xbrli:unit id="KRWEPS">
+    <xbrli:divide>
+      <xbrli:unitNumerator><xbrli:measure>iso4217:KRW</xbrli:measure></xbrli:unitNumerator>
+      <xbrli:unitDenominator><xbrli:measure>xbrli:shares</xbrli:measure></xbrli:unitDenominator>
+    </xbrli:divide>
+  </xbrli:unit>
+  <ifrs-full:Revenue contextRef="CFY2023dFY" unitRef="KRW" decimals="-6" id="r1">258935494000000</ifrs-full:Revenue>
+  <dart:OperatingIncomeLoss contextRef="CFY2023dFY" unitRef="KRW" decimals="-6">6566976000000</dart:OperatingIncomeLoss>
+  <ifrs-full:BasicEarningsLossPerShare contextRef="CFY2023dFY" unitRef="KRWEPS" decimals="INF">2131</ifrs-full:BasicEarningsLossPerShare>
+  <ifrs-full:Assets contextRef="CFY2023eFY" unitRef="KRW" decimals="-6">455905980000000</ifrs-full:Assets>
+  <ifrs-full:Goodwill contextRef="CFY2023eFY" unitRef="KRW" xsi:nil="true"/>
+</xbrli:xbrl>"#;
+
+    #[test]
+    fn from_reader_should_parse_facts_with_contexts_and_units() {
+        let instance = XbrlInstance::from_reader(INSTANCE.as_bytes()).unwrap();
+
+        assert_eq!(instance.contexts.len(), 2);
+        assert_eq!(instance.units.len(), 2);
+        assert_eq!(instance.facts.len(), 5);
+
+        let revenue = &instance.facts[0];
+        assert_eq!(revenue.id.as_deref(), Some("r1"));
+        assert_eq!(revenue.concept.account_id(), "ifrs-full_Revenue");
+        assert_eq!(
+            revenue.concept.namespace,
+            "http://xbrl.ifrs.org/taxonomy/2021-03-24/ifrs-full"
+        );
+        assert_eq!(
+            revenue.period,
+            Period::Duration {
+                start: NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+                end: NaiveDate::from_ymd_opt(2023, 12, 31).unwrap(),
+            }
+        );
+        assert_eq!(revenue.dimensions.len(), 1);
+        assert_eq!(
+            revenue.dimensions[0].dimension.local_name,
+            "ConsolidatedAndSeparateFinancialStatementsAxis"
+        );
+        assert_eq!(revenue.dimensions[0].member, "ifrs-full:ConsolidatedMember");
+        assert_eq!(revenue.decimals, Some(Decimals::Value(-6)));
+        assert_eq!(revenue.as_i64(), Some(258_935_494_000_000));
+        assert!(revenue.unit.as_ref().is_some_and(Unit::is_simple));
+
+        let eps = &instance.facts[2];
+        assert_eq!(eps.decimals, Some(Decimals::Infinite));
+        let eps_unit = eps.unit.as_ref().unwrap();
+        assert_eq!(eps_unit.numerators, vec!["iso4217:KRW"]);
+        assert_eq!(eps_unit.denominators, vec!["xbrli:shares"]);
+
+        let assets = &instance.facts[3];
+        assert!(assets.is_default_context());
+        assert_eq!(
+            assets.period,
+            Period::Instant(NaiveDate::from_ymd_opt(2023, 12, 31).unwrap())
+        );
+
+        let goodwill = &instance.facts[4];
+        assert!(goodwill.nil);
+        assert_eq!(goodwill.value, "");
+
+        assert_eq!(
+            instance
+                .facts_by_account_id("dart_OperatingIncomeLoss")
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn from_reader_should_fail_on_undefined_context() {
+        let xml = r#"<xbrli:xbrl xmlns:xbrli="http://www.xbrl.org/2003/instance" xmlns:ifrs-full="http://xbrl.ifrs.org/taxonomy/2021-03-24/ifrs-full">
+  <ifrs-full:Revenue contextRef="undefined" decimals="0">1</ifrs-full:Revenue>
+</xbrli:xbrl>"#;
+
+        assert!(XbrlInstance::from_reader(xml.as_bytes()).is_err());
+    }
+}
@@ -0,0 +1,140 @@
+//! # 무상증자 결정
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS005&apiId=2020024>
+//!
+//! 주요사항보고서 내에 무상증자 결정 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::CorpCls;
+use crate::endpoints::de::{amount, decimal, krw, optional_date};
+use crate::endpoints::dilution::Dilution;
+use crate::endpoints::identifiers::{CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use crate::validate::fields::yyyymmdd;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+impl OpenDartApi {
+    pub async fn get_fric_decsn(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<FricDecsn>>, OpenDartError> {
+        self.get(self.url("/api/fricDecsn.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub bgn_de: String,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub end_de: String,
+);
+
+json_body!(FricDecsn {
+    pub list: Vec<FricDecsnElement>,
+});
+
+derive_common! {
+    FricDecsnElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 신주의 종류와 수(보통주식 (주))
+        #[serde(default, deserialize_with = "amount")]
+        pub nstk_ostk_cnt: Reported<i64>,
+        /// 신주의 종류와 수(기타주식 (주))
+        #[serde(default, deserialize_with = "amount")]
+        pub nstk_estk_cnt: Reported<i64>,
+        /// 1주당 액면가액 (원)
+        #[serde(default, deserialize_with = "krw")]
+        pub fv_ps: Reported<i64>,
+        /// 증자전 발행주식총수(보통주식 (주))
+        #[serde(default, deserialize_with = "amount")]
+        pub bfic_tisstk_ostk: Reported<i64>,
+        /// 증자전 발행주식총수(기타주식 (주))
+        #[serde(default, deserialize_with = "amount")]
+        pub bfic_tisstk_estk: Reported<i64>,
+        /// 신주배정기준일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub nstk_asstd: Reported<NaiveDate>,
+        /// 1주당 신주배정 주식수(보통주식 (주))
+        #[serde(default, deserialize_with = "decimal")]
+        pub nstk_ascnt_ps_ostk: Reported<Decimal>,
+        /// 1주당 신주배정 주식수(기타주식 (주))
+        #[serde(default, deserialize_with = "decimal")]
+        pub nstk_ascnt_ps_estk: Reported<Decimal>,
+        /// 신주의 배당기산일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub nstk_dividrk: Reported<NaiveDate>,
+        /// 신주권교부예정일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub nstk_dlprd: Reported<NaiveDate>,
+        /// 신주의 상장 예정일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub nstk_lstprd: Reported<NaiveDate>,
+        /// 이사회결의일(결정일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub bddd: Reported<NaiveDate>,
+        /// 사외이사 참석여부(참석)
+        #[serde(default, deserialize_with = "amount")]
+        pub od_a_at_t: Reported<i64>,
+        /// 사외이사 참석여부(불참)
+        #[serde(default, deserialize_with = "amount")]
+        pub od_a_at_b: Reported<i64>,
+        /// 감사(사외이사가 아닌 감사위원) 참석여부
+        pub adt_a_atn: String,
+    }
+}
+
+impl Dilution for FricDecsnElement {
+    fn new_shares(&self) -> (Option<i64>, Option<i64>) {
+        (self.nstk_ostk_cnt.value(), self.nstk_estk_cnt.value())
+    }
+
+    fn shares_before(&self) -> (Option<i64>, Option<i64>) {
+        (self.bfic_tisstk_ostk.value(), self.bfic_tisstk_estk.value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de(mock::yyyymmdd())
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build FricDecsnRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bgn_de, mock::yyyymmdd());
+        assert_eq!(params.end_de, "20231231");
+    }
+
+    #[tokio::test]
+    async fn invalid_params_should_fail_before_making_request() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de("invalid")
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build FricDecsnRequestParams");
+
+        let api = OpenDartApi::default();
+        let response = api.get_fric_decsn(params).await;
+        assert!(response.is_err());
+    }
+}
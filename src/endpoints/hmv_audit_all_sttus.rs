@@ -0,0 +1,81 @@
+//! # 이사ㆍ감사 전체의 보수현황(보수지급금액 - 이사ㆍ감사 전체)
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS002&apiId=2019013>
+//!
+//! 정기보고서(사업, 분기, 반기보고서) 내에 이사ㆍ감사 전체의 보수현황(보수지급금액 - 이사ㆍ감사 전체) 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::{CorpCls, ReprtCode};
+use crate::endpoints::de::{amount, optional_date};
+use crate::endpoints::identifiers::{BsnsYear, CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use chrono::NaiveDate;
+
+impl OpenDartApi {
+    pub async fn get_hmv_audit_all_sttus(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<HmvAuditAllSttus>>, OpenDartError> {
+        self.get(self.url("/api/hmvAuditAllSttus.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    pub bsns_year: BsnsYear,
+    pub reprt_code: ReprtCode,
+);
+
+json_body!(HmvAuditAllSttus {
+    pub list: Vec<HmvAuditAllSttusElement>,
+});
+
+derive_common! {
+    HmvAuditAllSttusElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 인원수
+        #[serde(default, deserialize_with = "amount")]
+        pub nmpr: Reported<i64>,
+        /// 보수 총액
+        #[serde(default, deserialize_with = "amount")]
+        pub mendng_totamt: Reported<i64>,
+        /// 1인 평균 보수 액
+        #[serde(default, deserialize_with = "amount")]
+        pub jan_avrg_mendng_am: Reported<i64>,
+        /// 비고
+        pub rm: String,
+        /// 결산기준일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub stlm_dt: Reported<NaiveDate>,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bsns_year(mock::bsns_year())
+            .reprt_code(mock::reprt_code())
+            .build()
+            .expect("Failed to build HmvAuditAllSttusRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bsns_year, mock::bsns_year());
+        assert_eq!(params.reprt_code, mock::reprt_code());
+    }
+}
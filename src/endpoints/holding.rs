@@ -0,0 +1,75 @@
+//! Helpers for tracking ownership reports over time.
+//!
+//! Both the 대량보유 상황보고 (`majorstock`) and the 임원ㆍ주요주주 소유보고 (`elestock`)
+//! endpoints return one row per report, which can be ordered into a history per reporter.
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use std::collections::BTreeMap;
+
+/// A report of the shares held by a reporter (보고자) in a company.
+pub trait HoldingReport {
+    fn corp_code(&self) -> &str;
+    /// 보고자
+    fn reporter(&self) -> &str;
+    fn rcept_no(&self) -> &str;
+    fn rcept_dt(&self) -> NaiveDate;
+    /// Number of shares held after the report
+    fn shares(&self) -> Option<i64>;
+    /// Ownership ratio (%) after the report
+    fn ratio(&self) -> Option<Decimal>;
+}
+
+/// A report together with the holding delta from the reporter's previous report.
+#[derive(std::fmt::Debug, Clone, Copy, Eq, PartialEq)]
+pub struct HoldingChange<'a, R> {
+    pub report: &'a R,
+    pub previous: Option<&'a R>,
+    /// Change in the number of shares since the previous report
+    pub shares_delta: Option<i64>,
+    /// Change in the ownership ratio (%p) since the previous report
+    pub ratio_delta: Option<Decimal>,
+}
+
+/// Sorts the reports of each (`corp_code`, reporter) pair by date
+/// and computes the holding delta between consecutive reports.
+///
+/// Reports filed on the same date are ordered by `rcept_no`.
+/// The first report of each reporter has no delta.
+pub fn holding_history<R: HoldingReport>(
+    reports: &[R],
+) -> BTreeMap<(&str, &str), Vec<HoldingChange<'_, R>>> {
+    let mut grouped: BTreeMap<(&str, &str), Vec<&R>> = BTreeMap::new();
+    for report in reports {
+        grouped
+            .entry((report.corp_code(), report.reporter()))
+            .or_default()
+            .push(report);
+    }
+
+    grouped
+        .into_iter()
+        .map(|(key, mut reports)| {
+            reports.sort_by(|a, b| (a.rcept_dt(), a.rcept_no()).cmp(&(b.rcept_dt(), b.rcept_no())));
+
+            let mut previous: Option<&R> = None;
+            let changes = reports
+                .into_iter()
+                .map(|report| {
+                    let change = HoldingChange {
+                        report,
+                        previous,
+                        shares_delta: previous
+                            .and_then(|previous| Some(report.shares()? - previous.shares()?)),
+                        ratio_delta: previous
+                            .and_then(|previous| Some(report.ratio()? - previous.ratio()?)),
+                    };
+                    previous = Some(report);
+                    change
+                })
+                .collect();
+
+            (key, changes)
+        })
+        .collect()
+}
@@ -0,0 +1,86 @@
+//! # 최대주주 변동현황
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS002&apiId=2019008>
+//!
+//! 정기보고서(사업, 분기, 반기보고서) 내에 최대주주 변동현황 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::{CorpCls, ReprtCode};
+use crate::endpoints::de::{amount, decimal, optional_date};
+use crate::endpoints::identifiers::{BsnsYear, CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+impl OpenDartApi {
+    pub async fn get_hyslr_chg_sttus(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<HyslrChgSttus>>, OpenDartError> {
+        self.get(self.url("/api/hyslrChgSttus.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    pub bsns_year: BsnsYear,
+    pub reprt_code: ReprtCode,
+);
+
+json_body!(HyslrChgSttus {
+    pub list: Vec<HyslrChgSttusElement>,
+});
+
+derive_common! {
+    HyslrChgSttusElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 변동 일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub change_on: Reported<NaiveDate>,
+        /// 최대 주주 명
+        pub mxmm_shrholdr_nm: String,
+        /// 소유 주식 수
+        #[serde(default, deserialize_with = "amount")]
+        pub posesn_stock_co: Reported<i64>,
+        /// 지분 율
+        #[serde(default, deserialize_with = "decimal")]
+        pub qota_rt: Reported<Decimal>,
+        /// 변동 원인
+        pub change_cause: String,
+        /// 비고
+        pub rm: String,
+        /// 결산기준일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub stlm_dt: Reported<NaiveDate>,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bsns_year(mock::bsns_year())
+            .reprt_code(mock::reprt_code())
+            .build()
+            .expect("Failed to build HyslrChgSttusRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bsns_year, mock::bsns_year());
+        assert_eq!(params.reprt_code, mock::reprt_code());
+    }
+}
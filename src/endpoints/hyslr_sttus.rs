@@ -0,0 +1,116 @@
+//! # 최대주주 현황
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS002&apiId=2019007>
+//!
+//! 정기보고서(사업, 분기, 반기보고서) 내에 최대주주 현황 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::{CorpCls, ReprtCode};
+use crate::endpoints::de::{amount, decimal, optional_date};
+use crate::endpoints::identifiers::{BsnsYear, CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::share_class::ShareClass;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+impl OpenDartApi {
+    pub async fn get_hyslr_sttus(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<HyslrSttus>>, OpenDartError> {
+        self.get(self.url("/api/hyslrSttus.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    pub bsns_year: BsnsYear,
+    pub reprt_code: ReprtCode,
+);
+
+json_body!(HyslrSttus {
+    pub list: Vec<HyslrSttusElement>,
+});
+
+impl HyslrSttus {
+    /// Rows of the individual shareholders, without the total (`계`) rows.
+    pub fn shareholders(&self) -> impl Iterator<Item = &HyslrSttusElement> {
+        self.list.iter().filter(|element| !element.is_total())
+    }
+
+    /// Total (`계`) rows, one per stock kind.
+    pub fn totals(&self) -> impl Iterator<Item = &HyslrSttusElement> {
+        self.list.iter().filter(|element| element.is_total())
+    }
+}
+
+derive_common! {
+    HyslrSttusElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 성명
+        pub nm: String,
+        /// 관계
+        pub relate: String,
+        /// 주식 종류
+        pub stock_knd: ShareClass,
+        /// 기초 소유 주식 수
+        #[serde(default, deserialize_with = "amount")]
+        pub bsis_posesn_stock_co: Reported<i64>,
+        /// 기초 소유 주식 지분 율
+        #[serde(default, deserialize_with = "decimal")]
+        pub bsis_posesn_stock_qota_rt: Reported<Decimal>,
+        /// 기말 소유 주식 수
+        #[serde(default, deserialize_with = "amount")]
+        pub trmend_posesn_stock_co: Reported<i64>,
+        /// 기말 소유 주식 지분 율
+        #[serde(default, deserialize_with = "decimal")]
+        pub trmend_posesn_stock_qota_rt: Reported<Decimal>,
+        /// 비고
+        pub rm: String,
+        /// 결산기준일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub stlm_dt: Reported<NaiveDate>,
+    }
+}
+
+impl HyslrSttusElement {
+    /// Whether the row is the total (`계`) of the largest shareholder and its related parties.
+    pub fn is_total(&self) -> bool {
+        matches!(self.nm.trim(), "계" | "합계")
+    }
+
+    /// Whether the row is the largest shareholder itself, rather than a related party.
+    pub fn is_largest_shareholder(&self) -> bool {
+        matches!(self.relate.trim(), "본인" | "최대주주" | "최대주주 본인")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bsns_year(mock::bsns_year())
+            .reprt_code(mock::reprt_code())
+            .build()
+            .expect("Failed to build HyslrSttusRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bsns_year, mock::bsns_year());
+        assert_eq!(params.reprt_code, mock::reprt_code());
+    }
+}
@@ -0,0 +1,232 @@
+//! Identifiers shared by the request parameters and response bodies of several endpoints.
+//!
+//! Each identifier is validated when it's constructed or deserialized,
+//! so an invalid value fails before a request is made.
+
+use crate::utils::derive_newtype;
+use crate::validate::fields::{bsns_year, corp_code, rcept_no, stock_code};
+use chrono::NaiveDate;
+
+derive_newtype! {
+    #[validate(custom(function = corp_code))]
+    #[doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/docs/corp_code.md"))]
+    pub struct CorpCode(String);
+}
+
+/// Multiple `corp_code`s of the endpoints which support querying several companies at once.
+///
+/// Serializes to and deserializes from the comma separated form OpenDart expects, e.g. `00126380,00164779`.
+#[derive(std::fmt::Debug, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct CorpCodes(Vec<CorpCode>);
+
+impl CorpCodes {
+    pub fn iter(&self) -> std::slice::Iter<'_, CorpCode> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<CorpCode> for CorpCodes {
+    fn from(corp_code: CorpCode) -> Self {
+        Self(vec![corp_code])
+    }
+}
+
+impl From<Vec<CorpCode>> for CorpCodes {
+    fn from(corp_codes: Vec<CorpCode>) -> Self {
+        Self(corp_codes)
+    }
+}
+
+impl FromIterator<CorpCode> for CorpCodes {
+    fn from_iter<I: IntoIterator<Item = CorpCode>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl std::fmt::Display for CorpCodes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, corp_code) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            corp_code.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for CorpCodes {
+    type Err = validator::ValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',').map(CorpCode::new).collect()
+    }
+}
+
+impl serde::Serialize for CorpCodes {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for CorpCodes {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+derive_newtype! {
+    #[validate(custom(function = stock_code))]
+    #[doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/docs/stock_code.md"))]
+    pub struct StockCode(String);
+}
+
+impl StockCode {
+    /// The ISIN of the shares, e.g. `KR7005930003` for `005930` and `KR7005931001` for `005935`
+    ///
+    /// The last digit of the stock code is the share class, which becomes the class digit of the ISIN:
+    /// `0` for common shares and `5`, `7` and `9` for the first, second and third preferred shares.
+    /// Returns `None` for other classes and for alphanumeric stock codes, whose ISINs aren't derived this way.
+    pub fn isin(&self) -> Option<String> {
+        if !self.0.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let (base, class) = self.0.split_at(5);
+        let class = match class {
+            "0" => 0,
+            "5" => 1,
+            "7" => 2,
+            "9" => 3,
+            _ => return None,
+        };
+
+        let body = format!("KR7{base}{class}00");
+        let digits = body
+            .chars()
+            .map(|c| {
+                c.to_digit(36)
+                    .expect("The body should only contain ASCII alphanumerics")
+                    .to_string()
+            })
+            .collect::<String>();
+        let sum = digits
+            .chars()
+            .rev()
+            .enumerate()
+            .map(|(i, c)| {
+                let digit = c.to_digit(10).expect("The digits should be decimal");
+                if i % 2 == 0 {
+                    let doubled = digit * 2;
+                    doubled / 10 + doubled % 10
+                } else {
+                    digit
+                }
+            })
+            .sum::<u32>();
+        let check_digit = (10 - sum % 10) % 10;
+
+        Some(format!("{body}{check_digit}"))
+    }
+}
+
+derive_newtype! {
+    #[validate(custom(function = rcept_no))]
+    #[doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/docs/rcept_no.md"))]
+    pub struct RceptNo(String);
+}
+
+impl RceptNo {
+    /// The filing date, which is the first 8 digits of the receipt number
+    pub fn filing_date(&self) -> Option<NaiveDate> {
+        NaiveDate::parse_from_str(&self.0[..8], "%Y%m%d").ok()
+    }
+}
+
+derive_newtype! {
+    #[validate(custom(function = bsns_year))]
+    #[doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/docs/bsns_year.md"))]
+    pub struct BsnsYear(String);
+}
+
+impl BsnsYear {
+    pub fn year(&self) -> i32 {
+        self.0
+            .parse()
+            .expect("The value should have been validated as a year")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identifiers_should_be_validated() {
+        assert!(CorpCode::new("00126380").is_ok());
+        assert!(CorpCode::new("invalid").is_err());
+        assert!(CorpCode::new("0012638").is_err());
+        assert!(StockCode::new("005930").is_ok());
+        assert!(StockCode::new("0001A0").is_ok());
+        assert!(StockCode::new("").is_err());
+        assert!(RceptNo::new("20240312000736").is_ok());
+        assert!(RceptNo::new("2024031200073").is_err());
+        assert!(BsnsYear::new("2023").is_ok());
+        assert!(BsnsYear::new("1999").is_err());
+
+        assert_eq!(
+            serde_json::from_str::<CorpCode>(r#""00126380""#).unwrap(),
+            "00126380"
+        );
+        assert!(serde_json::from_str::<CorpCode>(r#""invalid""#).is_err());
+    }
+
+    #[test]
+    fn corp_codes_should_be_comma_separated() {
+        let corp_codes = [
+            CorpCode::new("00126380").unwrap(),
+            CorpCode::new("00164779").unwrap(),
+        ]
+        .into_iter()
+        .collect::<CorpCodes>();
+        assert_eq!(corp_codes.to_string(), "00126380,00164779");
+        assert_eq!(
+            serde_json::to_string(&corp_codes).unwrap(),
+            r#""00126380,00164779""#
+        );
+        assert_eq!(
+            serde_json::from_str::<CorpCodes>(r#""00126380,00164779""#).unwrap(),
+            corp_codes
+        );
+        assert!(serde_json::from_str::<CorpCodes>(r#""00126380,invalid""#).is_err());
+    }
+
+    #[test]
+    fn stock_code_isin_works() {
+        let isin = |stock_code: &str| StockCode::new(stock_code).unwrap().isin();
+        assert_eq!(isin("005930").as_deref(), Some("KR7005930003"));
+        assert_eq!(isin("000660").as_deref(), Some("KR7000660001"));
+        assert_eq!(isin("035420").as_deref(), Some("KR7035420009"));
+        assert_eq!(isin("005935").as_deref(), Some("KR7005931001"));
+        assert_eq!(isin("005387").as_deref(), Some("KR7005382007"));
+        assert_eq!(isin("005389").as_deref(), Some("KR7005383005"));
+        assert_eq!(isin("005931"), None);
+        assert_eq!(isin("0001A0"), None);
+    }
+
+    #[test]
+    fn rcept_no_filing_date_works() {
+        let rcept_no = RceptNo::new("20240312000736").unwrap();
+        assert_eq!(rcept_no.filing_date(), NaiveDate::from_ymd_opt(2024, 3, 12));
+        assert_eq!(RceptNo::new("20241332000736").unwrap().filing_date(), None);
+        assert_eq!(BsnsYear::new("2023").unwrap().year(), 2023);
+    }
+}
@@ -0,0 +1,125 @@
+//! # 개인별 보수지급 금액(5억이상 상위5인)
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS002&apiId=2019014>
+//!
+//! 정기보고서(사업, 분기, 반기보고서) 내에 개인별 보수지급 금액(5억이상 상위5인) 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::{CorpCls, ReprtCode};
+use crate::endpoints::de::{amount, optional_date};
+use crate::endpoints::identifiers::{BsnsYear, CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use chrono::NaiveDate;
+
+impl OpenDartApi {
+    pub async fn get_indvdl_by_pay(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<IndvdlByPay>>, OpenDartError> {
+        self.get(self.url("/api/indvdlByPay.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    pub bsns_year: BsnsYear,
+    pub reprt_code: ReprtCode,
+);
+
+json_body!(IndvdlByPay {
+    pub list: Vec<IndvdlByPayElement>,
+});
+
+impl IndvdlByPay {
+    /// Rows sorted by `mendng_totamt` in descending order.
+    pub fn ranked(&self) -> Vec<&IndvdlByPayElement> {
+        let mut ranked = self.list.iter().collect::<Vec<_>>();
+        ranked.sort_by_key(|element| std::cmp::Reverse(element.mendng_totamt.value()));
+        ranked
+    }
+}
+
+derive_common! {
+    IndvdlByPayElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 이름
+        pub nm: String,
+        /// 직위
+        pub ofcps: String,
+        /// 보수 총액
+        #[serde(default, deserialize_with = "amount")]
+        pub mendng_totamt: Reported<i64>,
+        /// 보수 총액 비 포함 보수
+        #[serde(default, deserialize_with = "amount")]
+        pub mendng_totamt_ct_incls_mendng: Reported<i64>,
+        /// 결산기준일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub stlm_dt: Reported<NaiveDate>,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+    use serde_json::json;
+
+    fn element(nm: &str, mendng_totamt: &str) -> serde_json::Value {
+        json!({
+            "rcept_no": mock::rcept_no(),
+            "corp_cls": "Y",
+            "corp_code": mock::corp_code(),
+            "corp_name": mock::corp_name(),
+            "nm": nm,
+            "ofcps": "대표이사",
+            "mendng_totamt": mendng_totamt,
+            "mendng_totamt_ct_incls_mendng": "-",
+            "stlm_dt": "2023-12-31",
+        })
+    }
+
+    #[test]
+    fn ranked_should_sort_by_total_compensation() {
+        let body: IndvdlByPay = serde_json::from_value(json!({
+            "status": "000",
+            "message": "정상",
+            "list": [
+                element("가나다", "1,234,000,000"),
+                element("라마바", "-"),
+                element("사아자", "5,678,000,000"),
+            ],
+        }))
+        .expect("Failed to deserialize IndvdlByPay");
+
+        let names = body
+            .ranked()
+            .iter()
+            .map(|element| element.nm.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["사아자", "가나다", "라마바"]);
+    }
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bsns_year(mock::bsns_year())
+            .reprt_code(mock::reprt_code())
+            .build()
+            .expect("Failed to build IndvdlByPayRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bsns_year, mock::bsns_year());
+        assert_eq!(params.reprt_code, mock::reprt_code());
+    }
+}
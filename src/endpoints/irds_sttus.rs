@@ -0,0 +1,140 @@
+//! # 증자(감자) 현황
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS002&apiId=2019004>
+//!
+//! 정기보고서(사업, 분기, 반기보고서) 내에 증자(감자) 현황 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::{CorpCls, ReprtCode};
+use crate::endpoints::de::{amount, optional_date};
+use crate::endpoints::identifiers::{BsnsYear, CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::share_class::ShareClass;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use chrono::NaiveDate;
+
+impl OpenDartApi {
+    pub async fn get_irds_sttus(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<IrdsSttus>>, OpenDartError> {
+        self.get(self.url("/api/irdsSttus.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    pub bsns_year: BsnsYear,
+    pub reprt_code: ReprtCode,
+);
+
+json_body!(IrdsSttus {
+    pub list: Vec<IrdsSttusElement>,
+});
+
+impl IrdsSttus {
+    /// Capital changes sorted by date, with undated rows (e.g. totals) last.
+    pub fn history(&self) -> Vec<&IrdsSttusElement> {
+        let mut history = self.list.iter().collect::<Vec<_>>();
+        history.sort_by_key(|element| {
+            (
+                element.isu_dcrs_de.value().is_none(),
+                element.isu_dcrs_de.value(),
+            )
+        });
+        history
+    }
+}
+
+derive_common! {
+    IrdsSttusElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 주식발행 감소일자
+        #[serde(default, deserialize_with = "optional_date")]
+        pub isu_dcrs_de: Reported<NaiveDate>,
+        /// 발행 감소 형태
+        pub isu_dcrs_stle: String,
+        /// 발행 감소 주식 종류
+        pub isu_dcrs_stock_knd: ShareClass,
+        /// 발행 감소 수량
+        #[serde(default, deserialize_with = "amount")]
+        pub isu_dcrs_qy: Reported<i64>,
+        /// 발행 감소 주당 액면 가액
+        #[serde(default, deserialize_with = "amount")]
+        pub isu_dcrs_mstvdv_fval_amount: Reported<i64>,
+        /// 발행 감소 주당 가액
+        #[serde(default, deserialize_with = "amount")]
+        pub isu_dcrs_mstvdv_amount: Reported<i64>,
+        /// 결산기준일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub stlm_dt: Reported<NaiveDate>,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+    use serde_json::json;
+
+    fn element(isu_dcrs_de: &str, isu_dcrs_stle: &str) -> serde_json::Value {
+        json!({
+            "rcept_no": mock::rcept_no(),
+            "corp_cls": "Y",
+            "corp_code": mock::corp_code(),
+            "corp_name": mock::corp_name(),
+            "isu_dcrs_de": isu_dcrs_de,
+            "isu_dcrs_stle": isu_dcrs_stle,
+            "isu_dcrs_stock_knd": "보통주",
+            "isu_dcrs_qy": "1,000,000",
+            "isu_dcrs_mstvdv_fval_amount": "500",
+            "isu_dcrs_mstvdv_amount": "-",
+            "stlm_dt": "2023-12-31",
+        })
+    }
+
+    #[test]
+    fn history_should_sort_by_date_with_undated_rows_last() {
+        let body: IrdsSttus = serde_json::from_value(json!({
+            "status": "000",
+            "message": "정상",
+            "list": [
+                element("-", "합계"),
+                element("2021.06.01", "유상증자(제3자배정)"),
+                element("2019.03.15", "주식분할"),
+            ],
+        }))
+        .expect("Failed to deserialize IrdsSttus");
+
+        let forms = body
+            .history()
+            .iter()
+            .map(|element| element.isu_dcrs_stle.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(forms, ["주식분할", "유상증자(제3자배정)", "합계"]);
+        assert_eq!(body.list[1].isu_dcrs_stock_knd, ShareClass::Common);
+    }
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bsns_year(mock::bsns_year())
+            .reprt_code(mock::reprt_code())
+            .build()
+            .expect("Failed to build IrdsSttusRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bsns_year, mock::bsns_year());
+        assert_eq!(params.reprt_code, mock::reprt_code());
+    }
+}
@@ -5,6 +5,9 @@
 
 use crate::client::OpenDartApi;
 use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::{CorpCls, PblntfDetailTy, PblntfTy, Sort, SortMth};
+use crate::endpoints::de::optional_identifier;
+use crate::endpoints::identifiers::{CorpCode, RceptNo, StockCode};
 use crate::endpoints::macros::{derive_common, json_body, params};
 use crate::endpoints::OpenDartResponse;
 use crate::error::OpenDartError;
@@ -21,8 +24,7 @@ impl OpenDartApi {
 
 params!(
     #[builder(default)]
-    #[validate(custom(function = "optional_corp_code"))]
-    pub corp_code: Option<String>,
+    pub corp_code: Option<CorpCode>,
     #[builder(default)]
     #[validate(custom(function = "optional_yyyymmdd"))]
     pub bgn_de: Option<String>,
@@ -33,20 +35,15 @@ params!(
     #[validate(custom(function = "optional_yes_no"))]
     pub last_reprt_at: Option<String>,
     #[builder(default)]
-    #[validate(custom(function = "optional_pblntf_ty"))]
-    pub pblntf_ty: Option<String>,
+    pub pblntf_ty: Option<PblntfTy>,
     #[builder(default)]
-    #[validate(custom(function = "optional_pblntf_detail_ty"))]
-    pub pblntf_detail_ty: Option<String>,
+    pub pblntf_detail_ty: Option<PblntfDetailTy>,
     #[builder(default)]
-    #[validate(custom(function = "optional_corp_cls"))]
-    pub corp_cls: Option<String>,
+    pub corp_cls: Option<CorpCls>,
     #[builder(default)]
-    #[validate(custom(function = "optional_sort"))]
-    pub sort: Option<String>,
+    pub sort: Option<Sort>,
     #[builder(default)]
-    #[validate(custom(function = "optional_sort_mth"))]
-    pub sort_mth: Option<String>,
+    pub sort_mth: Option<SortMth>,
     #[builder(default)]
     #[validate(range(min = 1))]
     pub page_no: Option<u64>,
@@ -66,12 +63,13 @@ json_body!(List {
 });
 
 derive_common!(ListElement {
-    corp_code: String,
+    corp_code: CorpCode,
     corp_name: String,
-    stock_code: String,
-    corp_cls: String,
+    #[serde(default, deserialize_with = "optional_identifier")]
+    stock_code: Option<StockCode>,
+    corp_cls: CorpCls,
     report_nm: String,
-    rcept_no: String,
+    rcept_no: RceptNo,
     flr_nm: String,
     rcept_dt: String,
     rm: String,
@@ -105,11 +103,11 @@ mod tests {
             .bgn_de(bgn_de.clone())
             .end_de(end_de.clone())
             .last_reprt_at(last_reprt_at.clone())
-            .pblntf_ty(pblntf_ty.clone())
-            .pblntf_detail_ty(pblntf_detail_ty.clone())
-            .corp_cls(corp_cls.clone())
-            .sort(sort.clone())
-            .sort_mth(sort_mth.clone())
+            .pblntf_ty(pblntf_ty)
+            .pblntf_detail_ty(pblntf_detail_ty)
+            .corp_cls(corp_cls)
+            .sort(sort)
+            .sort_mth(sort_mth)
             .page_no(page_no)
             .page_count(page_count)
             .build()
@@ -130,16 +128,23 @@ mod tests {
 
     #[tokio::test]
     async fn invalid_params_should_fail_before_making_request() {
+        assert!(CorpCode::new("invalid").is_err());
+        assert!("invalid".parse::<PblntfTy>().is_err());
+        assert!("invalid".parse::<PblntfDetailTy>().is_err());
+        assert!("invalid".parse::<CorpCls>().is_err());
+        assert!("invalid".parse::<Sort>().is_err());
+        assert!("invalid".parse::<SortMth>().is_err());
+
         let params = ParamsBuilder::default()
-            .corp_code("invalid".to_string())
+            .corp_code(mock::corp_code())
             .bgn_de("invalid".to_string())
             .end_de("invalid".to_string())
             .last_reprt_at("invalid".to_string())
-            .pblntf_ty("invalid".to_string())
-            .pblntf_detail_ty("invalid".to_string())
-            .corp_cls("invalid".to_string())
-            .sort("invalid".to_string())
-            .sort_mth("invalid".to_string())
+            .pblntf_ty(mock::pblntf_ty())
+            .pblntf_detail_ty(mock::pbntf_detail_ty())
+            .corp_cls(mock::corp_cls())
+            .sort(mock::sort())
+            .sort_mth(mock::sort_mth())
             .page_no(0_u64)
             .page_count(0_u64)
             .build()
@@ -0,0 +1,107 @@
+//! # 소송 등의 제기
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS005&apiId=2020028>
+//!
+//! 주요사항보고서 내에 소송 등의 제기 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::CorpCls;
+use crate::endpoints::de::{krw_in_text, optional_date};
+use crate::endpoints::identifiers::{CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use crate::validate::fields::yyyymmdd;
+use chrono::NaiveDate;
+
+impl OpenDartApi {
+    pub async fn get_lwst_lg(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<LwstLg>>, OpenDartError> {
+        self.get(self.url("/api/lwstLg.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub bgn_de: String,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub end_de: String,
+);
+
+json_body!(LwstLg {
+    pub list: Vec<LwstLgElement>,
+});
+
+derive_common! {
+    LwstLgElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 사건의 명칭
+        pub icnm: String,
+        /// 원고ㆍ신청인
+        pub ac_ap: String,
+        /// 청구내용
+        pub rq_cn: String,
+        /// 관할법원
+        pub cpct: String,
+        /// 향후대책
+        pub ft_ctp: String,
+        /// 제기일자
+        #[serde(default, deserialize_with = "optional_date")]
+        pub lgd: Reported<NaiveDate>,
+        /// 확인일자
+        #[serde(default, deserialize_with = "optional_date")]
+        pub cfd: Reported<NaiveDate>,
+    }
+}
+
+impl LwstLgElement {
+    /// The first KRW amount mentioned in `rq_cn` (청구내용), if any
+    pub fn claim_amount(&self) -> Option<i64> {
+        krw_in_text(&self.rq_cn)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de(mock::yyyymmdd())
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build LwstLgRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bgn_de, mock::yyyymmdd());
+        assert_eq!(params.end_de, "20231231");
+    }
+
+    #[tokio::test]
+    async fn invalid_params_should_fail_before_making_request() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de("invalid")
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build LwstLgRequestParams");
+
+        let api = OpenDartApi::default();
+        let response = api.get_lwst_lg(params).await;
+        assert!(response.is_err());
+    }
+}
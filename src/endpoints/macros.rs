@@ -112,7 +112,7 @@ macro_rules! json_body {
         $crate::endpoints::macros::derive_common!($struct_name {
             status: String,
             message: String,
-            $($field_vis $field_name: $field_type),*
+            $($(#[$field_attr])* $field_vis $field_name: $field_type),*
         });
 
         impl $crate::endpoints::ResponseCheck for $struct_name {
@@ -136,6 +136,52 @@ pub(crate) use json_body;
 
 // endregion: Response Body
 
+// region: Multiple Companies
+
+/// Adds the helpers of an endpoint which supports querying multiple companies at once:
+/// a `corp_codes` setter to its `ParamsBuilder` and `by_corp_code` to its response body.
+///
+/// The `corp_code` parameter of the endpoint is expected to be [`CorpCodes`](crate::endpoints::identifiers::CorpCodes).
+macro_rules! multi_corp_code {
+    ($body_name:ident, $element_name:ident) => {
+        impl ParamsBuilder {
+            /// Sets `corp_code` to multiple corp codes.
+            pub fn corp_codes<I>(&mut self, corp_codes: I) -> &mut Self
+            where
+                I: IntoIterator<Item = $crate::endpoints::identifiers::CorpCode>,
+            {
+                self.corp_code(
+                    corp_codes
+                        .into_iter()
+                        .collect::<$crate::endpoints::identifiers::CorpCodes>(),
+                )
+            }
+        }
+
+        impl $body_name {
+            /// Groups the rows by `corp_code`.
+            ///
+            /// The companies keep the order in which they were first received,
+            /// and so do the rows of each company.
+            pub fn by_corp_code(&self) -> Vec<(&str, Vec<&$element_name>)> {
+                let mut companies: Vec<(&str, Vec<&$element_name>)> = Vec::new();
+                for element in &self.list {
+                    let corp_code = element.corp_code.as_str();
+                    match companies.iter_mut().find(|(code, _)| *code == corp_code) {
+                        Some((_, elements)) => elements.push(element),
+                        None => companies.push((corp_code, vec![element])),
+                    }
+                }
+                companies
+            }
+        }
+    };
+}
+
+pub(crate) use multi_corp_code;
+
+// endregion: Multiple Companies
+
 #[cfg(test)]
 mod tests {
     use crate::test_utils::mock;
@@ -148,7 +194,7 @@ mod tests {
         );
 
         let corp_name = mock::corp_name();
-        let corp_code = mock::corp_code();
+        let corp_code = mock::corp_code().to_string();
         let params = ParamsBuilder::default()
             .corp_name(&corp_name)
             .corp_code(&corp_code)
@@ -175,7 +221,7 @@ mod tests {
         );
 
         let corp_name = mock::corp_name();
-        let corp_code = mock::corp_code();
+        let corp_code = mock::corp_code().to_string();
         let params = ParamsBuilder::default()
             .corp_name(&corp_name)
             .renamed_corp_code(&corp_code)
@@ -0,0 +1,181 @@
+//! # 대량보유 상황보고
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS004&apiId=2019021>
+//!
+//! 주식등의 대량보유상황보고서 내에 대량보유 상황보고 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::de::{amount, date, decimal};
+use crate::endpoints::holding::{holding_history, HoldingChange, HoldingReport};
+use crate::endpoints::identifiers::{CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use std::collections::BTreeMap;
+
+impl OpenDartApi {
+    pub async fn get_majorstock(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<Majorstock>>, OpenDartError> {
+        self.get(self.url("/api/majorstock.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+);
+
+json_body!(Majorstock {
+    pub list: Vec<MajorstockElement>,
+});
+
+impl Majorstock {
+    /// Report history of each (`corp_code`, `repror`) pair, sorted by date.
+    pub fn history(&self) -> BTreeMap<(&str, &str), Vec<HoldingChange<'_, MajorstockElement>>> {
+        holding_history(&self.list)
+    }
+}
+
+derive_common! {
+    MajorstockElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 접수일자
+        #[serde(deserialize_with = "date")]
+        pub rcept_dt: NaiveDate,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 보고구분
+        pub report_tp: String,
+        /// 대표보고자
+        pub repror: String,
+        /// 보유주식등의 수
+        #[serde(default, deserialize_with = "amount")]
+        pub stkqy: Reported<i64>,
+        /// 보유주식등의 증감
+        #[serde(default, deserialize_with = "amount")]
+        pub stkqy_irds: Reported<i64>,
+        /// 보유비율
+        #[serde(default, deserialize_with = "decimal")]
+        pub stkrt: Reported<Decimal>,
+        /// 보유비율 증감
+        #[serde(default, deserialize_with = "decimal")]
+        pub stkrt_irds: Reported<Decimal>,
+        /// 주요체결 주식등의 수
+        #[serde(default, deserialize_with = "amount")]
+        pub ctr_stkqy: Reported<i64>,
+        /// 주요체결 보유비율
+        #[serde(default, deserialize_with = "decimal")]
+        pub ctr_stkrt: Reported<Decimal>,
+        /// 보고사유
+        pub report_resn: String,
+    }
+}
+
+impl HoldingReport for MajorstockElement {
+    fn corp_code(&self) -> &str {
+        self.corp_code.as_str()
+    }
+
+    fn reporter(&self) -> &str {
+        &self.repror
+    }
+
+    fn rcept_no(&self) -> &str {
+        self.rcept_no.as_str()
+    }
+
+    fn rcept_dt(&self) -> NaiveDate {
+        self.rcept_dt
+    }
+
+    fn shares(&self) -> Option<i64> {
+        self.stkqy.value()
+    }
+
+    fn ratio(&self) -> Option<Decimal> {
+        self.stkrt.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    fn element(
+        rcept_no: &str,
+        rcept_dt: &str,
+        repror: &str,
+        stkqy: i64,
+        stkrt: &str,
+    ) -> MajorstockElement {
+        MajorstockElement {
+            rcept_no: rcept_no.parse().unwrap(),
+            rcept_dt: rcept_dt.parse().unwrap(),
+            corp_code: mock::corp_code(),
+            corp_name: "삼성전자".to_string(),
+            report_tp: "일반".to_string(),
+            repror: repror.to_string(),
+            stkqy: Reported::Parsed(stkqy),
+            stkqy_irds: Reported::Empty,
+            stkrt: Reported::Parsed(stkrt.parse().unwrap()),
+            stkrt_irds: Reported::Empty,
+            ctr_stkqy: Reported::Empty,
+            ctr_stkrt: Reported::Empty,
+            report_resn: "보유주식등의 수 변동".to_string(),
+        }
+    }
+
+    #[test]
+    fn history_should_sort_reports_and_compute_deltas() {
+        let majorstock = Majorstock {
+            status: "000".to_string(),
+            message: "정상".to_string(),
+            list: vec![
+                element(
+                    "20240105000100",
+                    "2024-01-05",
+                    "국민연금공단",
+                    450_000_000,
+                    "7.54",
+                ),
+                element(
+                    "20230105000100",
+                    "2023-01-05",
+                    "국민연금공단",
+                    500_000_000,
+                    "8.38",
+                ),
+                element(
+                    "20230210000200",
+                    "2023-02-10",
+                    "BlackRock Fund Advisors",
+                    300_000_000,
+                    "5.03",
+                ),
+            ],
+        };
+
+        let corp_code = mock::corp_code();
+        let history = majorstock.history();
+        assert_eq!(history.len(), 2);
+
+        let nps = &history[&(corp_code.as_str(), "국민연금공단")];
+        assert_eq!(nps.len(), 2);
+        assert_eq!(nps[0].report.rcept_no, "20230105000100");
+        assert_eq!(nps[0].shares_delta, None);
+        assert_eq!(nps[1].shares_delta, Some(-50_000_000));
+        assert_eq!(nps[1].ratio_delta, Some("-0.84".parse().unwrap()));
+
+        let blackrock = &history[&(corp_code.as_str(), "BlackRock Fund Advisors")];
+        assert_eq!(blackrock.len(), 1);
+        assert!(blackrock[0].previous.is_none());
+    }
+}
@@ -0,0 +1,108 @@
+//! Maturity bands of the outstanding balance (미상환 잔액) endpoints.
+//!
+//! Each endpoint reports remaining balances in its own set of bands,
+//! e.g. `de10_excess_de30_below` (10일 초과 30일 이하) or `yy1_excess_yy2_below` (1년 초과 2년 이하),
+//! which are exposed uniformly through [`MaturityBalance`].
+//!
+//! Balances are in the unit of the report, so converting them to 원 takes that unit,
+//! e.g. [`KrwUnit::Million`] for a report in 백만원.
+
+use crate::endpoints::macros::derive_common;
+use crate::endpoints::money::{KrwUnit, Money};
+
+/// Remaining maturity, which bounds a [`MaturityBucket`].
+#[derive(
+    std::fmt::Debug,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Hash,
+    // derive_more
+    derive_more::Display,
+    // serde
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub enum Maturity {
+    #[display("{_0}일")]
+    Days(u32),
+    #[display("{_0}년")]
+    Years(u32),
+}
+
+impl Maturity {
+    /// Approximate number of days, counting a year as 365 days.
+    pub fn days(self) -> u32 {
+        match self {
+            Maturity::Days(days) => days,
+            Maturity::Years(years) => years * 365,
+        }
+    }
+}
+
+impl Ord for Maturity {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.days().cmp(&other.days())
+    }
+}
+
+impl PartialOrd for Maturity {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+derive_common! {
+    /// Balance of a maturity band, which is `over` (exclusive) and `up_to` (inclusive) the bounds.
+    MaturityBucket {
+        /// Lower bound (초과). `None` for the shortest band.
+        pub over: Option<Maturity>,
+        /// Upper bound (이하). `None` for the longest band.
+        pub up_to: Option<Maturity>,
+        /// Remaining balance, in the unit of the report unless it states its own
+        pub balance: Option<Money>,
+    }
+}
+
+impl MaturityBucket {
+    pub fn new(over: Option<Maturity>, up_to: Option<Maturity>, balance: Option<Money>) -> Self {
+        Self {
+            over,
+            up_to,
+            balance,
+        }
+    }
+}
+
+/// A row of remaining balances by maturity band.
+pub trait MaturityBalance {
+    /// Balances of every band, from the shortest to the longest maturity.
+    fn buckets(&self) -> Vec<MaturityBucket>;
+
+    /// 합계
+    fn sum(&self) -> Option<Money>;
+
+    /// Balance in 원 maturing within `maturity`, summed over the bands whose upper bound is at most `maturity`,
+    /// where `report_unit` is the unit of the report.
+    fn due_within(&self, maturity: Maturity, report_unit: KrwUnit) -> i64 {
+        self.buckets()
+            .iter()
+            .filter(|bucket| bucket.up_to.is_some_and(|up_to| up_to <= maturity))
+            .filter_map(|bucket| bucket.balance?.krw(report_unit))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maturity_should_order_by_days() {
+        assert!(Maturity::Days(180) < Maturity::Years(1));
+        assert!(Maturity::Years(1) < Maturity::Days(400));
+        assert_eq!(Maturity::Days(10).to_string(), "10일");
+        assert_eq!(Maturity::Years(3).to_string(), "3년");
+    }
+}
@@ -0,0 +1,114 @@
+//! Amounts of money in the unit they're reported in.
+//!
+//! Periodic reports state amounts in a unit chosen by the filer, usually 백만원,
+//! and the DS002 endpoints return them without it, e.g. `"73,790"` for 73,790백만원.
+//! The unit isn't part of the response, so it has to be supplied to convert an amount to 원.
+
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::{Serialize, Serializer};
+use std::fmt;
+
+/// Unit of an amount of money in KRW
+#[derive(
+    std::fmt::Debug,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    // derive_more
+    derive_more::Display,
+)]
+pub enum KrwUnit {
+    #[display("원")]
+    Won,
+    #[display("천원")]
+    Thousand,
+    #[display("백만원")]
+    Million,
+    #[display("억원")]
+    HundredMillion,
+    #[display("조원")]
+    Trillion,
+}
+
+impl KrwUnit {
+    /// Every unit, from the largest to the smallest.
+    pub const ALL: [KrwUnit; 5] = [
+        KrwUnit::Trillion,
+        KrwUnit::HundredMillion,
+        KrwUnit::Million,
+        KrwUnit::Thousand,
+        KrwUnit::Won,
+    ];
+
+    /// Number of 원 in the unit
+    pub fn won(self) -> i64 {
+        match self {
+            KrwUnit::Won => 1,
+            KrwUnit::Thousand => 1_000,
+            KrwUnit::Million => 1_000_000,
+            KrwUnit::HundredMillion => 100_000_000,
+            KrwUnit::Trillion => 1_000_000_000_000,
+        }
+    }
+}
+
+/// An amount of money as reported, e.g. `73,790` or `8,850백만원`.
+#[derive(std::fmt::Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Money {
+    pub value: Decimal,
+    /// The unit stated next to the amount.
+    /// `None` when the amount is in the unit of the report.
+    pub unit: Option<KrwUnit>,
+}
+
+impl Money {
+    pub fn new(value: impl Into<Decimal>, unit: Option<KrwUnit>) -> Self {
+        Self {
+            value: value.into(),
+            unit,
+        }
+    }
+
+    /// The amount in 원, where `report_unit` is the unit of the report
+    /// for amounts which are reported without one.
+    pub fn krw(&self, report_unit: KrwUnit) -> Option<i64> {
+        let unit = self.unit.unwrap_or(report_unit);
+        (self.value * Decimal::from(unit.won())).round().to_i64()
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.unit {
+            Some(unit) => write!(f, "{}{unit}", self.value),
+            None => self.value.fmt(f),
+        }
+    }
+}
+
+impl Serialize for Money {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn money_should_convert_to_krw_in_its_own_unit_first() {
+        let in_report_unit = Money::new(73_790, None);
+        assert_eq!(in_report_unit.krw(KrwUnit::Million), Some(73_790_000_000));
+        assert_eq!(in_report_unit.krw(KrwUnit::Won), Some(73_790));
+
+        let with_unit = Money::new(Decimal::new(15, 1), Some(KrwUnit::HundredMillion));
+        assert_eq!(with_unit.krw(KrwUnit::Million), Some(150_000_000));
+        assert_eq!(with_unit.to_string(), "1.5억원");
+    }
+}
@@ -0,0 +1,94 @@
+//! # 소액주주 현황
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS002&apiId=2019009>
+//!
+//! 정기보고서(사업, 분기, 반기보고서) 내에 소액주주 현황 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::{CorpCls, ReprtCode};
+use crate::endpoints::de::{amount, decimal, optional_date};
+use crate::endpoints::identifiers::{BsnsYear, CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+impl OpenDartApi {
+    pub async fn get_mrhl_sttus(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<MrhlSttus>>, OpenDartError> {
+        self.get(self.url("/api/mrhlSttus.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    pub bsns_year: BsnsYear,
+    pub reprt_code: ReprtCode,
+);
+
+json_body!(MrhlSttus {
+    pub list: Vec<MrhlSttusElement>,
+});
+
+derive_common! {
+    MrhlSttusElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 구분 (e.g. 소액주주)
+        ///
+        /// This is the category of shareholders rather than a class of shares,
+        /// so it isn't a [`ShareClass`](crate::endpoints::share_class::ShareClass).
+        pub se: String,
+        /// 주주 수
+        #[serde(default, deserialize_with = "amount")]
+        pub shrholdr_co: Reported<i64>,
+        /// 전체 주주 수
+        #[serde(default, deserialize_with = "amount")]
+        pub shrholdr_tot_co: Reported<i64>,
+        /// 주주 비율
+        #[serde(default, deserialize_with = "decimal")]
+        pub shrholdr_rate: Reported<Decimal>,
+        /// 보유 주식 수
+        #[serde(default, deserialize_with = "amount")]
+        pub hold_stock_co: Reported<i64>,
+        /// 총발행 주식수
+        #[serde(default, deserialize_with = "amount")]
+        pub stock_tot_co: Reported<i64>,
+        /// 보유 주식 비율
+        #[serde(default, deserialize_with = "decimal")]
+        pub hold_stock_rate: Reported<Decimal>,
+        /// 결산기준일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub stlm_dt: Reported<NaiveDate>,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bsns_year(mock::bsns_year())
+            .reprt_code(mock::reprt_code())
+            .build()
+            .expect("Failed to build MrhlSttusRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bsns_year, mock::bsns_year());
+        assert_eq!(params.reprt_code, mock::reprt_code());
+    }
+}
@@ -0,0 +1,178 @@
+//! # 타법인 출자현황
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS002&apiId=2019015>
+//!
+//! 정기보고서(사업, 분기, 반기보고서) 내에 타법인 출자현황 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::{CorpCls, ReprtCode};
+use crate::endpoints::de::{amount, decimal, optional_date};
+use crate::endpoints::identifiers::{BsnsYear, CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+impl OpenDartApi {
+    pub async fn get_otr_cpr_invstmnt_sttus(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<OtrCprInvstmntSttus>>, OpenDartError> {
+        self.get(self.url("/api/otrCprInvstmntSttus.json"), args)
+            .await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    pub bsns_year: BsnsYear,
+    pub reprt_code: ReprtCode,
+);
+
+json_body!(OtrCprInvstmntSttus {
+    pub list: Vec<OtrCprInvstmntSttusElement>,
+});
+
+impl OtrCprInvstmntSttus {
+    /// Rows of the investees, without the total (`합계`) row.
+    pub fn investees(&self) -> impl Iterator<Item = &OtrCprInvstmntSttusElement> {
+        self.list.iter().filter(|element| !element.is_total())
+    }
+
+    /// Investees whose ownership ratio at the end of the period is at least `ratio` (%),
+    /// sorted by the ratio in descending order.
+    pub fn owned_at_least(&self, ratio: Decimal) -> Vec<&OtrCprInvstmntSttusElement> {
+        let mut investees = self
+            .investees()
+            .filter(|element| {
+                element
+                    .trmend_blce_qota_rt
+                    .value()
+                    .is_some_and(|rt| rt >= ratio)
+            })
+            .collect::<Vec<_>>();
+        investees.sort_by_key(|element| std::cmp::Reverse(element.trmend_blce_qota_rt.value()));
+        investees
+    }
+}
+
+derive_common! {
+    OtrCprInvstmntSttusElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 법인명
+        pub inv_prm: String,
+        /// 최초 취득 일자
+        pub frst_acqs_de: String,
+        /// 출자 목적
+        pub invstmnt_purps: String,
+        /// 최초 취득 금액
+        #[serde(default, deserialize_with = "amount")]
+        pub frst_acqs_amount: Reported<i64>,
+        /// 기초 잔액 수량
+        #[serde(default, deserialize_with = "amount")]
+        pub bsis_blce_qy: Reported<i64>,
+        /// 기초 잔액 지분 율
+        #[serde(default, deserialize_with = "decimal")]
+        pub bsis_blce_qota_rt: Reported<Decimal>,
+        /// 기초 잔액 장부 가액
+        #[serde(default, deserialize_with = "amount")]
+        pub bsis_blce_acntbk_amount: Reported<i64>,
+        /// 증가 감소 취득 처분 수량
+        #[serde(default, deserialize_with = "amount")]
+        pub incrs_dcrs_acqs_dsps_qy: Reported<i64>,
+        /// 증가 감소 취득 처분 금액
+        #[serde(default, deserialize_with = "amount")]
+        pub incrs_dcrs_acqs_dsps_amount: Reported<i64>,
+        /// 증가 감소 평가 손액
+        #[serde(default, deserialize_with = "amount")]
+        pub incrs_dcrs_evl_lstmn: Reported<i64>,
+        /// 기말 잔액 수량
+        #[serde(default, deserialize_with = "amount")]
+        pub trmend_blce_qy: Reported<i64>,
+        /// 기말 잔액 지분 율
+        #[serde(default, deserialize_with = "decimal")]
+        pub trmend_blce_qota_rt: Reported<Decimal>,
+        /// 기말 잔액 장부 가액
+        #[serde(default, deserialize_with = "amount")]
+        pub trmend_blce_acntbk_amount: Reported<i64>,
+        /// 최근 사업 연도 재무 현황 총 자산
+        #[serde(default, deserialize_with = "amount")]
+        pub recent_bsns_year_fnnr_sttus_tot_assets: Reported<i64>,
+        /// 최근 사업 연도 재무 현황 당기 순이익
+        #[serde(default, deserialize_with = "amount")]
+        pub recent_bsns_year_fnnr_sttus_thstrm_ntpf: Reported<i64>,
+        /// 결산기준일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub stlm_dt: Reported<NaiveDate>,
+    }
+}
+
+impl OtrCprInvstmntSttusElement {
+    pub fn is_total(&self) -> bool {
+        matches!(self.inv_prm.trim(), "합계" | "계" | "총계")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn owned_at_least_should_exclude_total_and_sort_by_ratio() {
+        let element = |inv_prm: &str, rt: &str| {
+            serde_json::json!({
+                "rcept_no": mock::rcept_no(),
+                "corp_cls": mock::corp_cls(),
+                "corp_code": mock::corp_code(),
+                "corp_name": mock::corp_name(),
+                "inv_prm": inv_prm,
+                "frst_acqs_de": "-",
+                "invstmnt_purps": "경영참여",
+                "trmend_blce_qota_rt": rt,
+            })
+        };
+        let body: OtrCprInvstmntSttus = serde_json::from_value(serde_json::json!({
+            "status": "000",
+            "message": "정상",
+            "list": [
+                element("삼성전기", "23.7"),
+                element("삼성디스플레이", "84.8"),
+                element("삼성중공업", "15.2"),
+                element("합계", "-"),
+            ],
+        }))
+        .expect("Failed to deserialize OtrCprInvstmntSttus");
+
+        let owned = body.owned_at_least(Decimal::from(20));
+        let names = owned
+            .iter()
+            .map(|element| element.inv_prm.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["삼성디스플레이", "삼성전기"]);
+        assert_eq!(body.investees().count(), 3);
+    }
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bsns_year(mock::bsns_year())
+            .reprt_code(mock::reprt_code())
+            .build()
+            .expect("Failed to build OtrCprInvstmntSttusRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bsns_year, mock::bsns_year());
+        assert_eq!(params.reprt_code, mock::reprt_code());
+    }
+}
@@ -0,0 +1,86 @@
+//! # 사외이사 및 그 변동현황
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS002&apiId=2020012>
+//!
+//! 정기보고서(사업, 분기, 반기보고서) 내에 사외이사 및 그 변동현황 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::{CorpCls, ReprtCode};
+use crate::endpoints::de::{amount, optional_date};
+use crate::endpoints::identifiers::{BsnsYear, CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use chrono::NaiveDate;
+
+impl OpenDartApi {
+    pub async fn get_outcmpny_drctr_nd_change_sttus(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<OutcmpnyDrctrNdChangeSttus>>, OpenDartError> {
+        self.get(self.url("/api/outcmpnyDrctrNdChangeSttus.json"), args)
+            .await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    pub bsns_year: BsnsYear,
+    pub reprt_code: ReprtCode,
+);
+
+json_body!(OutcmpnyDrctrNdChangeSttus {
+    pub list: Vec<OutcmpnyDrctrNdChangeSttusElement>,
+});
+
+derive_common! {
+    OutcmpnyDrctrNdChangeSttusElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 이사의 수
+        #[serde(default, deserialize_with = "amount")]
+        pub drctr_co: Reported<i64>,
+        /// 사외이사 수
+        #[serde(default, deserialize_with = "amount")]
+        pub otcmp_drctr_co: Reported<i64>,
+        /// 사외이사 변동현황(선임)
+        #[serde(default, deserialize_with = "amount")]
+        pub apnt: Reported<i64>,
+        /// 사외이사 변동현황(해임)
+        #[serde(default, deserialize_with = "amount")]
+        pub rlsofc: Reported<i64>,
+        /// 사외이사 변동현황(중도퇴임)
+        #[serde(default, deserialize_with = "amount")]
+        pub mdstrm_resig: Reported<i64>,
+        /// 결산기준일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub stlm_dt: Reported<NaiveDate>,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bsns_year(mock::bsns_year())
+            .reprt_code(mock::reprt_code())
+            .build()
+            .expect("Failed to build OutcmpnyDrctrNdChangeSttusRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bsns_year, mock::bsns_year());
+        assert_eq!(params.reprt_code, mock::reprt_code());
+    }
+}
@@ -0,0 +1,113 @@
+//! # 해외 증권시장 주권등 상장폐지 결정
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS005&apiId=2020030>
+//!
+//! 주요사항보고서 내에 해외 증권시장 주권등 상장폐지 결정 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::CorpCls;
+use crate::endpoints::de::{amount, optional_date};
+use crate::endpoints::identifiers::{CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use crate::validate::fields::yyyymmdd;
+use chrono::NaiveDate;
+
+impl OpenDartApi {
+    pub async fn get_ov_dlst_decsn(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<OvDlstDecsn>>, OpenDartError> {
+        self.get(self.url("/api/ovDlstDecsn.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub bgn_de: String,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub end_de: String,
+);
+
+json_body!(OvDlstDecsn {
+    pub list: Vec<OvDlstDecsnElement>,
+});
+
+derive_common! {
+    OvDlstDecsnElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 상장폐지주식 종류 및 수(보통주식)
+        #[serde(default, deserialize_with = "amount")]
+        pub dlststk_ostk_cnt: Reported<i64>,
+        /// 상장폐지주식 종류 및 수(기타주식)
+        #[serde(default, deserialize_with = "amount")]
+        pub dlststk_cstk_cnt: Reported<i64>,
+        /// 상장거래소(소재국가)
+        pub lstex_nt: String,
+        /// 상장거래소(증권거래소)
+        pub stk_ltrs: String,
+        /// 폐지신청예정일자
+        #[serde(default, deserialize_with = "optional_date")]
+        pub dlstrq_prd: Reported<NaiveDate>,
+        /// 폐지(예정)일자
+        #[serde(default, deserialize_with = "optional_date")]
+        pub dlst_prd: Reported<NaiveDate>,
+        /// 폐지사유
+        pub dlst_rs: String,
+        /// 이사회결의일(결정일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub bddd: Reported<NaiveDate>,
+        /// 사외이사 참석여부(참석)
+        #[serde(default, deserialize_with = "amount")]
+        pub od_a_at_t: Reported<i64>,
+        /// 사외이사 참석여부(불참)
+        #[serde(default, deserialize_with = "amount")]
+        pub od_a_at_b: Reported<i64>,
+        /// 감사(사외이사가 아닌 감사위원) 참석여부
+        pub adt_a_atn: String,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de(mock::yyyymmdd())
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build OvDlstDecsnRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bgn_de, mock::yyyymmdd());
+        assert_eq!(params.end_de, "20231231");
+    }
+
+    #[tokio::test]
+    async fn invalid_params_should_fail_before_making_request() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de("invalid")
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build OvDlstDecsnRequestParams");
+
+        let api = OpenDartApi::default();
+        let response = api.get_ov_dlst_decsn(params).await;
+        assert!(response.is_err());
+    }
+}
@@ -0,0 +1,102 @@
+//! # 해외 증권시장 주권등 상장
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS005&apiId=2020031>
+//!
+//! 주요사항보고서 내에 해외 증권시장 주권등 상장 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::CorpCls;
+use crate::endpoints::de::{amount, optional_date};
+use crate::endpoints::identifiers::{CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use crate::validate::fields::yyyymmdd;
+use chrono::NaiveDate;
+
+impl OpenDartApi {
+    pub async fn get_ov_lst(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<OvLst>>, OpenDartError> {
+        self.get(self.url("/api/ovLst.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub bgn_de: String,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub end_de: String,
+);
+
+json_body!(OvLst {
+    pub list: Vec<OvLstElement>,
+});
+
+derive_common! {
+    OvLstElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 상장주식 종류 및 수(보통주식)
+        #[serde(default, deserialize_with = "amount")]
+        pub lststk_ostk_cnt: Reported<i64>,
+        /// 상장주식 종류 및 수(기타주식)
+        #[serde(default, deserialize_with = "amount")]
+        pub lststk_cstk_cnt: Reported<i64>,
+        /// 상장거래소(소재국가)
+        pub lstex_nt: String,
+        /// 상장거래소(증권거래소)
+        pub stk_ltrs: String,
+        /// 종목 명(code)
+        pub stk_cd: String,
+        /// 상장일자
+        #[serde(default, deserialize_with = "optional_date")]
+        pub lstd: Reported<NaiveDate>,
+        /// 확인일자
+        #[serde(default, deserialize_with = "optional_date")]
+        pub cfd: Reported<NaiveDate>,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de(mock::yyyymmdd())
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build OvLstRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bgn_de, mock::yyyymmdd());
+        assert_eq!(params.end_de, "20231231");
+    }
+
+    #[tokio::test]
+    async fn invalid_params_should_fail_before_making_request() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de("invalid")
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build OvLstRequestParams");
+
+        let api = OpenDartApi::default();
+        let response = api.get_ov_lst(params).await;
+        assert!(response.is_err());
+    }
+}
@@ -0,0 +1,110 @@
+//! # 해외 증권시장 주권등 상장 결정
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS005&apiId=2020029>
+//!
+//! 주요사항보고서 내에 해외 증권시장 주권등 상장 결정 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::CorpCls;
+use crate::endpoints::de::{amount, optional_date};
+use crate::endpoints::identifiers::{CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use crate::validate::fields::yyyymmdd;
+use chrono::NaiveDate;
+
+impl OpenDartApi {
+    pub async fn get_ov_lst_decsn(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<OvLstDecsn>>, OpenDartError> {
+        self.get(self.url("/api/ovLstDecsn.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub bgn_de: String,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub end_de: String,
+);
+
+json_body!(OvLstDecsn {
+    pub list: Vec<OvLstDecsnElement>,
+});
+
+derive_common! {
+    OvLstDecsnElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 상장주식 종류 및 수(보통주식)
+        #[serde(default, deserialize_with = "amount")]
+        pub lststk_ostk_cnt: Reported<i64>,
+        /// 상장주식 종류 및 수(기타주식)
+        #[serde(default, deserialize_with = "amount")]
+        pub lststk_cstk_cnt: Reported<i64>,
+        /// 상장거래소(소재국가)
+        pub lstex_nt: String,
+        /// 상장거래소(증권거래소)
+        pub stk_ltrs: String,
+        /// 상장목적
+        pub lstpp: String,
+        /// 상장예정일자
+        #[serde(default, deserialize_with = "optional_date")]
+        pub lstprd: Reported<NaiveDate>,
+        /// 이사회결의일(결정일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub bddd: Reported<NaiveDate>,
+        /// 사외이사 참석여부(참석)
+        #[serde(default, deserialize_with = "amount")]
+        pub od_a_at_t: Reported<i64>,
+        /// 사외이사 참석여부(불참)
+        #[serde(default, deserialize_with = "amount")]
+        pub od_a_at_b: Reported<i64>,
+        /// 감사(사외이사가 아닌 감사위원) 참석여부
+        pub adt_a_atn: String,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de(mock::yyyymmdd())
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build OvLstDecsnRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bgn_de, mock::yyyymmdd());
+        assert_eq!(params.end_de, "20231231");
+    }
+
+    #[tokio::test]
+    async fn invalid_params_should_fail_before_making_request() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de("invalid")
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build OvLstDecsnRequestParams");
+
+        let api = OpenDartApi::default();
+        let response = api.get_ov_lst_decsn(params).await;
+        assert!(response.is_err());
+    }
+}
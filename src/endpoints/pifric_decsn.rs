@@ -0,0 +1,246 @@
+//! # 유무상증자 결정
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS005&apiId=2020025>
+//!
+//! 주요사항보고서 내에 유무상증자 결정 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::CorpCls;
+use crate::endpoints::de::{amount, decimal, krw, optional_date};
+use crate::endpoints::dilution::Dilution;
+use crate::endpoints::identifiers::{CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use crate::validate::fields::yyyymmdd;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+impl OpenDartApi {
+    pub async fn get_pifric_decsn(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<PifricDecsn>>, OpenDartError> {
+        self.get(self.url("/api/pifricDecsn.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub bgn_de: String,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub end_de: String,
+);
+
+json_body!(PifricDecsn {
+    pub list: Vec<PifricDecsnElement>,
+});
+
+derive_common! {
+    PifricDecsnElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 유상증자 신주의 종류와 수(보통주식 (주))
+        #[serde(default, deserialize_with = "amount")]
+        pub piic_nstk_ostk_cnt: Reported<i64>,
+        /// 유상증자 신주의 종류와 수(기타주식 (주))
+        #[serde(default, deserialize_with = "amount")]
+        pub piic_nstk_estk_cnt: Reported<i64>,
+        /// 유상증자 1주당 액면가액 (원)
+        #[serde(default, deserialize_with = "krw")]
+        pub piic_fv_ps: Reported<i64>,
+        /// 유상증자 증자전 발행주식총수 (주)(보통주식 (주))
+        #[serde(default, deserialize_with = "amount")]
+        pub piic_bfic_tisstk_ostk: Reported<i64>,
+        /// 유상증자 증자전 발행주식총수 (주)(기타주식 (주))
+        #[serde(default, deserialize_with = "amount")]
+        pub piic_bfic_tisstk_estk: Reported<i64>,
+        /// 유상증자 자금조달의 목적(시설자금 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub piic_fdpp_fclt: Reported<i64>,
+        /// 유상증자 자금조달의 목적(영업양수자금 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub piic_fdpp_bsninh: Reported<i64>,
+        /// 유상증자 자금조달의 목적(운영자금 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub piic_fdpp_op: Reported<i64>,
+        /// 유상증자 자금조달의 목적(채무상환자금 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub piic_fdpp_dtrp: Reported<i64>,
+        /// 유상증자 자금조달의 목적(타법인 증권 취득자금 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub piic_fdpp_ocsa: Reported<i64>,
+        /// 유상증자 자금조달의 목적(기타자금 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub piic_fdpp_etc: Reported<i64>,
+        /// 유상증자 증자방식
+        pub piic_ic_mthn: String,
+        /// 무상증자 신주의 종류와 수(보통주식 (주))
+        #[serde(default, deserialize_with = "amount")]
+        pub fric_nstk_ostk_cnt: Reported<i64>,
+        /// 무상증자 신주의 종류와 수(기타주식 (주))
+        #[serde(default, deserialize_with = "amount")]
+        pub fric_nstk_estk_cnt: Reported<i64>,
+        /// 무상증자 1주당 액면가액 (원)
+        #[serde(default, deserialize_with = "krw")]
+        pub fric_fv_ps: Reported<i64>,
+        /// 무상증자 증자전 발행주식총수(보통주식 (주))
+        #[serde(default, deserialize_with = "amount")]
+        pub fric_bfic_tisstk_ostk: Reported<i64>,
+        /// 무상증자 증자전 발행주식총수(기타주식 (주))
+        #[serde(default, deserialize_with = "amount")]
+        pub fric_bfic_tisstk_estk: Reported<i64>,
+        /// 무상증자 신주배정기준일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub fric_nstk_asstd: Reported<NaiveDate>,
+        /// 무상증자 1주당 신주배정 주식수(보통주식 (주))
+        #[serde(default, deserialize_with = "decimal")]
+        pub fric_nstk_ascnt_ps_ostk: Reported<Decimal>,
+        /// 무상증자 1주당 신주배정 주식수(기타주식 (주))
+        #[serde(default, deserialize_with = "decimal")]
+        pub fric_nstk_ascnt_ps_estk: Reported<Decimal>,
+        /// 무상증자 신주의 배당기산일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub fric_nstk_dividrk: Reported<NaiveDate>,
+        /// 무상증자 신주권교부예정일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub fric_nstk_dlprd: Reported<NaiveDate>,
+        /// 무상증자 신주의 상장 예정일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub fric_nstk_lstprd: Reported<NaiveDate>,
+        /// 무상증자 이사회결의일(결정일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub fric_bddd: Reported<NaiveDate>,
+        /// 무상증자 사외이사 참석여부(참석)
+        #[serde(default, deserialize_with = "amount")]
+        pub fric_od_a_at_t: Reported<i64>,
+        /// 무상증자 사외이사 참석여부(불참)
+        #[serde(default, deserialize_with = "amount")]
+        pub fric_od_a_at_b: Reported<i64>,
+        /// 무상증자 감사(사외이사가 아닌 감사위원) 참석여부
+        pub fric_adt_a_atn: String,
+        /// 공매도 해당여부
+        pub ssl_at: String,
+        /// 공매도 시작일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub ssl_bgd: Reported<NaiveDate>,
+        /// 공매도 종료일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub ssl_edd: Reported<NaiveDate>,
+    }
+}
+
+impl PifricDecsnElement {
+    /// Dilution of the paid-in part (유상증자)
+    pub fn piic(&self) -> impl Dilution {
+        PifricPart {
+            new_shares: (
+                self.piic_nstk_ostk_cnt.value(),
+                self.piic_nstk_estk_cnt.value(),
+            ),
+            shares_before: (
+                self.piic_bfic_tisstk_ostk.value(),
+                self.piic_bfic_tisstk_estk.value(),
+            ),
+        }
+    }
+
+    /// Dilution of the bonus part (무상증자)
+    pub fn fric(&self) -> impl Dilution {
+        PifricPart {
+            new_shares: (
+                self.fric_nstk_ostk_cnt.value(),
+                self.fric_nstk_estk_cnt.value(),
+            ),
+            shares_before: (
+                self.fric_bfic_tisstk_ostk.value(),
+                self.fric_bfic_tisstk_estk.value(),
+            ),
+        }
+    }
+}
+
+/// Combined dilution of the paid-in and bonus parts,
+/// relative to the shares issued before the paid-in increase.
+impl Dilution for PifricDecsnElement {
+    fn new_shares(&self) -> (Option<i64>, Option<i64>) {
+        let add = |a: Option<i64>, b: Option<i64>| match (a, b) {
+            (None, None) => None,
+            (a, b) => Some(a.unwrap_or_default() + b.unwrap_or_default()),
+        };
+        (
+            add(
+                self.piic_nstk_ostk_cnt.value(),
+                self.fric_nstk_ostk_cnt.value(),
+            ),
+            add(
+                self.piic_nstk_estk_cnt.value(),
+                self.fric_nstk_estk_cnt.value(),
+            ),
+        )
+    }
+
+    fn shares_before(&self) -> (Option<i64>, Option<i64>) {
+        (
+            self.piic_bfic_tisstk_ostk.value(),
+            self.piic_bfic_tisstk_estk.value(),
+        )
+    }
+}
+
+struct PifricPart {
+    new_shares: (Option<i64>, Option<i64>),
+    shares_before: (Option<i64>, Option<i64>),
+}
+
+impl Dilution for PifricPart {
+    fn new_shares(&self) -> (Option<i64>, Option<i64>) {
+        self.new_shares
+    }
+
+    fn shares_before(&self) -> (Option<i64>, Option<i64>) {
+        self.shares_before
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de(mock::yyyymmdd())
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build PifricDecsnRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bgn_de, mock::yyyymmdd());
+        assert_eq!(params.end_de, "20231231");
+    }
+
+    #[tokio::test]
+    async fn invalid_params_should_fail_before_making_request() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de("invalid")
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build PifricDecsnRequestParams");
+
+        let api = OpenDartApi::default();
+        let response = api.get_pifric_decsn(params).await;
+        assert!(response.is_err());
+    }
+}
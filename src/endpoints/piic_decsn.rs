@@ -0,0 +1,158 @@
+//! # 유상증자 결정
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS005&apiId=2020023>
+//!
+//! 주요사항보고서 내에 유상증자 결정 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::CorpCls;
+use crate::endpoints::de::{amount, krw, optional_date};
+use crate::endpoints::dilution::Dilution;
+use crate::endpoints::identifiers::{CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use crate::validate::fields::yyyymmdd;
+use chrono::NaiveDate;
+
+impl OpenDartApi {
+    pub async fn get_piic_decsn(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<PiicDecsn>>, OpenDartError> {
+        self.get(self.url("/api/piicDecsn.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub bgn_de: String,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub end_de: String,
+);
+
+json_body!(PiicDecsn {
+    pub list: Vec<PiicDecsnElement>,
+});
+
+derive_common! {
+    PiicDecsnElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 신주의 종류와 수(보통주식 (주))
+        #[serde(default, deserialize_with = "amount")]
+        pub nstk_ostk_cnt: Reported<i64>,
+        /// 신주의 종류와 수(기타주식 (주))
+        #[serde(default, deserialize_with = "amount")]
+        pub nstk_estk_cnt: Reported<i64>,
+        /// 1주당 액면가액 (원)
+        #[serde(default, deserialize_with = "krw")]
+        pub fv_ps: Reported<i64>,
+        /// 증자전 발행주식총수 (주)(보통주식 (주))
+        #[serde(default, deserialize_with = "amount")]
+        pub bfic_tisstk_ostk: Reported<i64>,
+        /// 증자전 발행주식총수 (주)(기타주식 (주))
+        #[serde(default, deserialize_with = "amount")]
+        pub bfic_tisstk_estk: Reported<i64>,
+        /// 자금조달의 목적(시설자금 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub fdpp_fclt: Reported<i64>,
+        /// 자금조달의 목적(영업양수자금 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub fdpp_bsninh: Reported<i64>,
+        /// 자금조달의 목적(운영자금 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub fdpp_op: Reported<i64>,
+        /// 자금조달의 목적(채무상환자금 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub fdpp_dtrp: Reported<i64>,
+        /// 자금조달의 목적(타법인 증권 취득자금 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub fdpp_ocsa: Reported<i64>,
+        /// 자금조달의 목적(기타자금 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub fdpp_etc: Reported<i64>,
+        /// 증자방식
+        pub ic_mthn: String,
+        /// 공매도 해당여부
+        pub ssl_at: String,
+        /// 공매도 시작일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub ssl_bgd: Reported<NaiveDate>,
+        /// 공매도 종료일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub ssl_edd: Reported<NaiveDate>,
+    }
+}
+
+impl Dilution for PiicDecsnElement {
+    fn new_shares(&self) -> (Option<i64>, Option<i64>) {
+        (self.nstk_ostk_cnt.value(), self.nstk_estk_cnt.value())
+    }
+
+    fn shares_before(&self) -> (Option<i64>, Option<i64>) {
+        (self.bfic_tisstk_ostk.value(), self.bfic_tisstk_estk.value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+    use rust_decimal::Decimal;
+
+    #[test]
+    fn dilution_should_compare_new_shares_to_shares_before() {
+        let element: PiicDecsnElement = serde_json::from_value(serde_json::json!({
+            "rcept_no": mock::rcept_no(),
+            "corp_cls": "K",
+            "corp_code": mock::corp_code(),
+            "corp_name": mock::corp_name(),
+            "nstk_ostk_cnt": "2,000,000",
+            "nstk_estk_cnt": "-",
+            "bfic_tisstk_ostk": "10,000,000",
+            "bfic_tisstk_estk": "-",
+            "ic_mthn": "주주배정후 실권주 일반공모",
+            "ssl_at": "미해당",
+        }))
+        .expect("Failed to deserialize PiicDecsnElement");
+
+        assert_eq!(element.dilution(), Some(Decimal::from(20)));
+    }
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de(mock::yyyymmdd())
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build PiicDecsnRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bgn_de, mock::yyyymmdd());
+        assert_eq!(params.end_de, "20231231");
+    }
+
+    #[tokio::test]
+    async fn invalid_params_should_fail_before_making_request() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de("invalid")
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build PiicDecsnRequestParams");
+
+        let api = OpenDartApi::default();
+        let response = api.get_piic_decsn(params).await;
+        assert!(response.is_err());
+    }
+}
@@ -0,0 +1,97 @@
+//! # 사모자금의 사용내역
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS002&apiId=2020017>
+//!
+//! 정기보고서(사업, 분기, 반기보고서) 내에 사모자금의 사용내역 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::{CorpCls, ReprtCode};
+use crate::endpoints::de::{money, optional_date};
+use crate::endpoints::identifiers::{BsnsYear, CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::money::Money;
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use chrono::NaiveDate;
+
+impl OpenDartApi {
+    pub async fn get_prvsrp_cptal_use_dtls(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<PrvsrpCptalUseDtls>>, OpenDartError> {
+        self.get(self.url("/api/prvsrpCptalUseDtls.json"), args)
+            .await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    pub bsns_year: BsnsYear,
+    pub reprt_code: ReprtCode,
+);
+
+json_body!(PrvsrpCptalUseDtls {
+    pub list: Vec<PrvsrpCptalUseDtlsElement>,
+});
+
+derive_common! {
+    PrvsrpCptalUseDtlsElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 구분
+        pub se_nm: String,
+        /// 회차
+        pub tm: String,
+        /// 납입일
+        pub pay_de: String,
+        /// 납입금액
+        #[serde(default, deserialize_with = "money")]
+        pub pay_amount: Reported<Money>,
+        /// 자금사용 계획
+        pub cptal_use_plan: String,
+        /// 실제 자금사용 현황
+        pub real_cptal_use_sttus: String,
+        /// 주요사항보고서의 자금사용 계획(사용용도)
+        pub mtrpt_cptal_use_plan_useprps: String,
+        /// 주요사항보고서의 자금사용 계획(조달금액)
+        #[serde(default, deserialize_with = "money")]
+        pub mtrpt_cptal_use_plan_prcure_amount: Reported<Money>,
+        /// 실제 자금사용 내역(내용)
+        pub real_cptal_use_dtls_cn: String,
+        /// 실제 자금사용 내역(금액)
+        #[serde(default, deserialize_with = "money")]
+        pub real_cptal_use_dtls_amount: Reported<Money>,
+        /// 차이발생 사유 등
+        pub dffrnc_occrrnc_resn: String,
+        /// 결산기준일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub stlm_dt: Reported<NaiveDate>,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bsns_year(mock::bsns_year())
+            .reprt_code(mock::reprt_code())
+            .build()
+            .expect("Failed to build PrvsrpCptalUseDtlsRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bsns_year, mock::bsns_year());
+        assert_eq!(params.reprt_code, mock::reprt_code());
+    }
+}
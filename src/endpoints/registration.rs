@@ -0,0 +1,199 @@
+//! Sections shared by the 증권신고서 (securities registration statement) summaries.
+//!
+//! Each summary groups its rows by `title`, e.g. 일반사항 or 인수인정보.
+//! The sections which several summaries have in common are defined here.
+
+use crate::endpoints::codes::CorpCls;
+use crate::endpoints::de::{amount, krw};
+use crate::endpoints::identifiers::{CorpCode, RceptNo};
+use crate::endpoints::macros::derive_common;
+use crate::endpoints::reported::Reported;
+
+derive_common! {
+    /// 인수인정보
+    Underwriter {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 증권의종류
+        pub stksen: String,
+        /// 인수인구분
+        pub actsen: String,
+        /// 인수인명
+        pub actnmn: String,
+        /// 인수수량
+        #[serde(default, deserialize_with = "amount")]
+        pub udtcnt: Reported<i64>,
+        /// 인수금액
+        #[serde(default, deserialize_with = "krw")]
+        pub udtamt: Reported<i64>,
+        /// 인수대가
+        pub udtprc: String,
+        /// 인수방법
+        pub udtmth: String,
+    }
+}
+
+derive_common! {
+    /// 자금의사용목적
+    FundUsage {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 구분
+        pub se: String,
+        /// 금액
+        #[serde(default, deserialize_with = "krw")]
+        pub amt: Reported<i64>,
+    }
+}
+
+derive_common! {
+    /// 매출인에관한사항
+    Seller {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 보유자
+        pub hdr: String,
+        /// 회사와의관계
+        pub rl_cmp: String,
+        /// 매출전보유증권수
+        #[serde(default, deserialize_with = "amount")]
+        pub bfsl_hdstk: Reported<i64>,
+        /// 매출증권수
+        #[serde(default, deserialize_with = "amount")]
+        pub slstk: Reported<i64>,
+        /// 매출후보유증권수
+        #[serde(default, deserialize_with = "amount")]
+        pub atsl_hdstk: Reported<i64>,
+    }
+}
+
+derive_common! {
+    /// 일반청약자환매청구권
+    RepurchaseRight {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 부여사유
+        pub grtrs: String,
+        /// 행사가능 투자자
+        pub exavivr: String,
+        /// 부여수량
+        #[serde(default, deserialize_with = "amount")]
+        pub grtcnt: Reported<i64>,
+        /// 행사기간
+        pub expd: String,
+        /// 행사가격
+        #[serde(default, deserialize_with = "krw")]
+        pub exprc: Reported<i64>,
+    }
+}
+
+derive_common! {
+    /// 증권의종류
+    OfferedSecurity {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 증권의종류
+        pub stksen: String,
+        /// 증권수량
+        #[serde(default, deserialize_with = "amount")]
+        pub stkcnt: Reported<i64>,
+        /// 액면가액
+        #[serde(default, deserialize_with = "krw")]
+        pub fv: Reported<i64>,
+        /// 모집(매출)가액
+        #[serde(default, deserialize_with = "krw")]
+        pub slprc: Reported<i64>,
+        /// 모집(매출)총액
+        #[serde(default, deserialize_with = "krw")]
+        pub slta: Reported<i64>,
+        /// 모집(매출)방법
+        pub slmthn: String,
+    }
+}
+
+derive_common! {
+    /// 발행증권
+    IssuedSecurity {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 종류
+        pub kndn: String,
+        /// 수량
+        #[serde(default, deserialize_with = "amount")]
+        pub cnt: Reported<i64>,
+        /// 액면가액
+        #[serde(default, deserialize_with = "krw")]
+        pub fv: Reported<i64>,
+        /// 모집(매출)가액
+        #[serde(default, deserialize_with = "krw")]
+        pub slprc: Reported<i64>,
+        /// 모집(매출)총액
+        #[serde(default, deserialize_with = "krw")]
+        pub slta: Reported<i64>,
+    }
+}
+
+derive_common! {
+    /// 당사회사에관한사항
+    Party {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 회사명
+        pub cmpnm: String,
+        /// 구분
+        pub sen: String,
+        /// 총자산
+        #[serde(default, deserialize_with = "krw")]
+        pub tast: Reported<i64>,
+        /// 자본금
+        #[serde(default, deserialize_with = "krw")]
+        pub cpt: Reported<i64>,
+        /// 발행주식 종류
+        pub isstk_knd: String,
+        /// 발행주식수
+        #[serde(default, deserialize_with = "amount")]
+        pub isstk_cnt: Reported<i64>,
+    }
+}
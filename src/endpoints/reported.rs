@@ -0,0 +1,86 @@
+//! Values of numeric and date columns as reported by filers.
+
+use serde::{Serialize, Serializer};
+
+/// A numeric or date column of a response, as reported by the filer.
+///
+/// Filers type free text such as `미정` or `해당사항 없음` in these columns,
+/// so a value which can't be parsed is kept as [`Reported::Raw`]
+/// instead of failing the whole response.
+#[derive(std::fmt::Debug, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Reported<T> {
+    /// Empty value or a placeholder like `-`
+    #[default]
+    Empty,
+    /// The parsed value
+    Parsed(T),
+    /// Text which isn't a value of the column, e.g. `미정`
+    Raw(String),
+}
+
+impl<T> Reported<T> {
+    pub fn parsed(&self) -> Option<&T> {
+        match self {
+            Reported::Parsed(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn raw(&self) -> Option<&str> {
+        match self {
+            Reported::Raw(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Reported::Empty)
+    }
+
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Reported<U> {
+        match self {
+            Reported::Empty => Reported::Empty,
+            Reported::Parsed(value) => Reported::Parsed(f(value)),
+            Reported::Raw(text) => Reported::Raw(text),
+        }
+    }
+}
+
+impl<T: Copy> Reported<T> {
+    /// The parsed value, or `None` when the value is empty or isn't parsable.
+    pub fn value(&self) -> Option<T> {
+        self.parsed().copied()
+    }
+}
+
+impl<T> From<T> for Reported<T> {
+    fn from(value: T) -> Self {
+        Reported::Parsed(value)
+    }
+}
+
+impl<T> From<Option<T>> for Reported<T> {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Reported::Empty, Reported::Parsed)
+    }
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for Reported<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Reported::Empty => Ok(()),
+            Reported::Parsed(value) => value.fmt(f),
+            Reported::Raw(text) => f.write_str(text),
+        }
+    }
+}
+
+impl<T: Serialize> Serialize for Reported<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Reported::Empty => serializer.serialize_none(),
+            Reported::Parsed(value) => value.serialize(serializer),
+            Reported::Raw(text) => serializer.serialize_str(text),
+        }
+    }
+}
@@ -0,0 +1,151 @@
+//! Shared types of the merger, split, split-merger and share exchange decision reports.
+
+use crate::error::ParseRatioError;
+use rust_decimal::Decimal;
+use std::fmt;
+use std::str::FromStr;
+
+/// A ratio such as 합병비율 or 교환비율, reported as e.g. `"1 : 0.1234567"`.
+#[derive(std::fmt::Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ExchangeRatio {
+    pub left: Decimal,
+    pub right: Decimal,
+}
+
+impl ExchangeRatio {
+    /// `right` per unit of `left`, e.g. the number of the counterparty's shares per share of the company.
+    pub fn per_unit(&self) -> Option<Decimal> {
+        if self.left.is_zero() {
+            return None;
+        }
+        Some(self.right / self.left)
+    }
+}
+
+impl FromStr for ExchangeRatio {
+    type Err = ParseRatioError;
+
+    /// Parses the numbers on both sides of the first `:` which has a number on each side,
+    /// ignoring thousands separators and any surrounding words such as company names.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let is_number = |c: char| c.is_ascii_digit() || c == '.' || c == ',';
+        let parse = |value: &str| Decimal::from_str(&value.replace(',', "")).ok();
+
+        s.match_indices(':')
+            .find_map(|(i, _)| {
+                let left = s[..i].trim_end();
+                let start = left
+                    .char_indices()
+                    .rev()
+                    .find(|(_, c)| !is_number(*c))
+                    .map_or(0, |(i, c)| i + c.len_utf8());
+                let right = s[i + 1..].trim_start();
+                let end = right.find(|c| !is_number(c)).unwrap_or(right.len());
+
+                Some(Self {
+                    left: parse(&left[start..])?,
+                    right: parse(&right[..end])?,
+                })
+            })
+            .ok_or_else(|| ParseRatioError {
+                value: s.to_string(),
+            })
+    }
+}
+
+impl fmt::Display for ExchangeRatio {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} : {}", self.left, self.right)
+    }
+}
+
+/// 외부평가에 관한 사항
+#[derive(std::fmt::Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct ExternalValuation<'a> {
+    /// Whether an external valuation was obtained.
+    /// `None` when `exevl_atn` isn't a plain yes or no.
+    pub obtained: Option<bool>,
+    /// 근거 및 사유
+    pub basis: &'a str,
+    /// 외부평가기관의 명칭
+    pub appraiser: &'a str,
+    /// 외부평가 기간
+    pub period: &'a str,
+    /// 외부평가 의견
+    pub opinion: &'a str,
+}
+
+impl<'a> ExternalValuation<'a> {
+    pub(crate) fn new(
+        exevl_atn: &'a str,
+        exevl_bs_rs: &'a str,
+        exevl_intn: &'a str,
+        exevl_pd: &'a str,
+        exevl_op: &'a str,
+    ) -> Self {
+        let obtained = match exevl_atn.trim() {
+            "예" | "해당" | "Y" | "y" => Some(true),
+            "아니오" | "아니요" | "미해당" | "N" | "n" => Some(false),
+            _ => None,
+        };
+        Self {
+            obtained,
+            basis: exevl_bs_rs,
+            appraiser: exevl_intn,
+            period: exevl_pd,
+            opinion: exevl_op,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exchange_ratio_should_parse_reported_formats() {
+        let cases = [
+            ("1 : 0.1234567", "1", "0.1234567"),
+            ("1:2", "1", "2"),
+            ("합병법인 : 피합병법인 = 1 : 0.5 (보통주 기준)", "1", "0.5"),
+            ("합병비율1:0.25", "1", "0.25"),
+            ("1,000 : 1", "1000", "1"),
+        ];
+
+        for (value, left, right) in cases {
+            let ratio = ExchangeRatio::from_str(value).expect("Failed to parse ratio");
+            assert_eq!(
+                ratio.left,
+                Decimal::from_str(left).unwrap(),
+                "value: {value}"
+            );
+            assert_eq!(
+                ratio.right,
+                Decimal::from_str(right).unwrap(),
+                "value: {value}"
+            );
+        }
+
+        assert!(ExchangeRatio::from_str("해당사항 없음").is_err());
+        assert_eq!(
+            ExchangeRatio::from_str("2 : 1").unwrap().per_unit(),
+            Some(Decimal::new(5, 1))
+        );
+    }
+
+    #[test]
+    fn external_valuation_should_read_yes_or_no() {
+        let valuation = ExternalValuation::new("예", "-", "삼일회계법인", "-", "적정");
+        assert_eq!(valuation.obtained, Some(true));
+        assert_eq!(valuation.appraiser, "삼일회계법인");
+
+        assert_eq!(
+            ExternalValuation::new("아니오", "-", "-", "-", "-").obtained,
+            Some(false)
+        );
+        assert_eq!(
+            ExternalValuation::new("-", "-", "-", "-", "-").obtained,
+            None
+        );
+    }
+}
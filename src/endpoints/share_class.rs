@@ -0,0 +1,103 @@
+//! Classes of shares reported in the `se` and `stock_knd` columns of periodic reports.
+
+use std::fmt;
+
+/// 주식의 종류
+///
+/// Filers describe the same class in slightly different words (e.g. `보통주` and `보통주식`),
+/// so known spellings are normalized, and anything else is kept as [`ShareClass::Other`].
+#[derive(
+    std::fmt::Debug,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    // serde
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[serde(from = "String", into = "String")]
+pub enum ShareClass {
+    /// 보통주
+    Common,
+    /// 우선주
+    Preferred,
+    /// 합계
+    Total,
+    /// Any other class, such as 종류주식 or 비고 rows
+    Other(String),
+}
+
+impl ShareClass {
+    pub fn is_total(&self) -> bool {
+        matches!(self, ShareClass::Total)
+    }
+}
+
+impl From<String> for ShareClass {
+    fn from(value: String) -> Self {
+        let normalized = value
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>();
+        match normalized.as_str() {
+            "보통주" | "보통주식" => ShareClass::Common,
+            "우선주" | "우선주식" => ShareClass::Preferred,
+            "합계" | "계" | "총계" => ShareClass::Total,
+            _ => ShareClass::Other(value),
+        }
+    }
+}
+
+impl From<&str> for ShareClass {
+    fn from(value: &str) -> Self {
+        ShareClass::from(value.to_string())
+    }
+}
+
+impl From<ShareClass> for String {
+    fn from(value: ShareClass) -> Self {
+        value.to_string()
+    }
+}
+
+impl fmt::Display for ShareClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShareClass::Common => f.write_str("보통주"),
+            ShareClass::Preferred => f.write_str("우선주"),
+            ShareClass::Total => f.write_str("합계"),
+            ShareClass::Other(value) => f.write_str(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn share_class_should_normalize_known_spellings() {
+        let cases = [
+            ("보통주", ShareClass::Common),
+            ("보통주식", ShareClass::Common),
+            ("우선주", ShareClass::Preferred),
+            ("합 계", ShareClass::Total),
+            ("계", ShareClass::Total),
+            ("종류주식", ShareClass::Other("종류주식".to_string())),
+        ];
+
+        for (value, expected) in cases {
+            let json = serde_json::to_string(value).unwrap();
+            let parsed: ShareClass = serde_json::from_str(&json).expect("Failed to deserialize");
+            assert_eq!(parsed, expected, "value: {value}");
+        }
+
+        assert_eq!(
+            serde_json::to_string(&ShareClass::Preferred).unwrap(),
+            r#""우선주""#
+        );
+    }
+}
@@ -0,0 +1,228 @@
+//! # 주식교환·이전 결정
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS005&apiId=2020054>
+//!
+//! 주요사항보고서 내에 주식교환·이전 결정 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::CorpCls;
+use crate::endpoints::de::{amount, krw, optional_date};
+use crate::endpoints::identifiers::{CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::restructuring::{ExchangeRatio, ExternalValuation};
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use crate::validate::fields::yyyymmdd;
+use chrono::NaiveDate;
+
+impl OpenDartApi {
+    pub async fn get_stk_extr_decsn(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<StkExtrDecsn>>, OpenDartError> {
+        self.get(self.url("/api/stkExtrDecsn.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub bgn_de: String,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub end_de: String,
+);
+
+json_body!(StkExtrDecsn {
+    pub list: Vec<StkExtrDecsnElement>,
+});
+
+derive_common! {
+    StkExtrDecsnElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 구분
+        pub extr_sen: String,
+        /// 교환ㆍ이전 형태
+        pub extr_stn: String,
+        /// 교환ㆍ이전 대상법인(회사명)
+        pub extr_tgcmp_cmpnm: String,
+        /// 교환ㆍ이전 대상법인(대표자)
+        pub extr_tgcmp_rp: String,
+        /// 교환ㆍ이전 대상법인(주요사업)
+        pub extr_tgcmp_mbsn: String,
+        /// 교환ㆍ이전 대상법인(회사와의 관계)
+        pub extr_tgcmp_rl_cmpn: String,
+        /// 교환ㆍ이전 대상법인(발행주식총수(주)(보통주식))
+        #[serde(default, deserialize_with = "amount")]
+        pub extr_tgcmp_tisstk_ostk: Reported<i64>,
+        /// 교환ㆍ이전 대상법인(발행주식총수(주)(종류주식))
+        #[serde(default, deserialize_with = "amount")]
+        pub extr_tgcmp_tisstk_cstk: Reported<i64>,
+        /// 대상법인 최근 사업연도 재무내용(자산총계 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub rbsnfdtl_tast: Reported<i64>,
+        /// 대상법인 최근 사업연도 재무내용(부채총계 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub rbsnfdtl_tdbt: Reported<i64>,
+        /// 대상법인 최근 사업연도 재무내용(자본총계 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub rbsnfdtl_teqt: Reported<i64>,
+        /// 대상법인 최근 사업연도 재무내용(자본금 (원))
+        #[serde(default, deserialize_with = "krw")]
+        pub rbsnfdtl_cpt: Reported<i64>,
+        /// 교환ㆍ이전 비율
+        pub extr_rt: String,
+        /// 교환ㆍ이전 비율 산출근거
+        pub extr_rt_bs: String,
+        /// 외부평가에 관한 사항(외부평가 여부)
+        pub exevl_atn: String,
+        /// 외부평가에 관한 사항(근거 및 사유)
+        pub exevl_bs_rs: String,
+        /// 외부평가에 관한 사항(외부평가기관의 명칭)
+        pub exevl_intn: String,
+        /// 외부평가에 관한 사항(외부평가 기간)
+        pub exevl_pd: String,
+        /// 외부평가에 관한 사항(외부평가 의견)
+        pub exevl_op: String,
+        /// 교환ㆍ이전 목적
+        pub extr_pp: String,
+        /// 교환ㆍ이전일정(교환ㆍ이전계약일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub extrsc_extrctrd: Reported<NaiveDate>,
+        /// 교환ㆍ이전일정(주주확정기준일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub extrsc_shddstd: Reported<NaiveDate>,
+        /// 교환ㆍ이전일정(주주명부 폐쇄기간(시작일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub extrsc_shclspd_bgd: Reported<NaiveDate>,
+        /// 교환ㆍ이전일정(주주명부 폐쇄기간(종료일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub extrsc_shclspd_edd: Reported<NaiveDate>,
+        /// 교환ㆍ이전일정(주식교환ㆍ이전 반대의사 통지접수기간(시작일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub extrsc_extrop_rcpd_bgd: Reported<NaiveDate>,
+        /// 교환ㆍ이전일정(주식교환ㆍ이전 반대의사 통지접수기간(종료일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub extrsc_extrop_rcpd_edd: Reported<NaiveDate>,
+        /// 교환ㆍ이전일정(주주총회 예정일자)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub extrsc_gmtsck_prd: Reported<NaiveDate>,
+        /// 교환ㆍ이전일정(주식매수청구권 행사기간(시작일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub extrsc_aprskh_expd_bgd: Reported<NaiveDate>,
+        /// 교환ㆍ이전일정(주식매수청구권 행사기간(종료일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub extrsc_aprskh_expd_edd: Reported<NaiveDate>,
+        /// 교환ㆍ이전일정(구주권 제출기간(시작일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub extrsc_osprpd_bgd: Reported<NaiveDate>,
+        /// 교환ㆍ이전일정(구주권 제출기간(종료일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub extrsc_osprpd_edd: Reported<NaiveDate>,
+        /// 교환ㆍ이전일정(매매거래정지 예정기간(시작일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub extrsc_trspprpd_bgd: Reported<NaiveDate>,
+        /// 교환ㆍ이전일정(매매거래정지 예정기간(종료일))
+        #[serde(default, deserialize_with = "optional_date")]
+        pub extrsc_trspprpd_edd: Reported<NaiveDate>,
+        /// 교환ㆍ이전일정(교환ㆍ이전일자)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub extrsc_extrdt: Reported<NaiveDate>,
+        /// 교환ㆍ이전일정(신주권교부예정일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub extrsc_nstkdlprd: Reported<NaiveDate>,
+        /// 교환ㆍ이전일정(신주의 상장예정일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub extrsc_nstklstprd: Reported<NaiveDate>,
+        /// 교환ㆍ이전 후 완전모회사명
+        pub atextr_pcmp_cmpnm: String,
+        /// 교환ㆍ이전 후 완전모회사 상장유지 여부
+        pub atextr_pcmp_lstmn_atn: String,
+        /// 타법인의 우회상장 요건 충족여부
+        pub otcpr_bdlst_sf_atn: String,
+        /// 주식매수청구권에 관한 사항(매수예정가격)
+        pub aprskh_plnprc: String,
+        /// 주식매수청구권에 관한 사항(지급예정시기, 지급방법)
+        pub aprskh_pym_plpd_mth: String,
+        /// 주식매수청구권에 관한 사항(계약에 미치는 효력)
+        pub aprskh_ctref: String,
+        /// 이사회결의일(결정일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub bddd: Reported<NaiveDate>,
+        /// 사외이사 참석여부(참석)
+        #[serde(default, deserialize_with = "amount")]
+        pub od_a_at_t: Reported<i64>,
+        /// 사외이사 참석여부(불참)
+        #[serde(default, deserialize_with = "amount")]
+        pub od_a_at_b: Reported<i64>,
+        /// 감사(사외이사가 아닌 감사위원) 참석여부
+        pub adt_a_atn: String,
+        /// 풋옵션 등 계약 체결여부
+        pub popt_ctr_atn: String,
+        /// 계약내용
+        pub popt_ctr_cn: String,
+        /// 증권신고서 제출대상 여부
+        pub rs_sm_atn: String,
+        /// 제출을 면제받은 경우 그 사유
+        pub ex_sm_r: String,
+    }
+}
+
+impl StkExtrDecsnElement {
+    /// 교환ㆍ이전 비율, parsed from `extr_rt`
+    pub fn exchange_ratio(&self) -> Option<ExchangeRatio> {
+        self.extr_rt.parse().ok()
+    }
+
+    /// 외부평가에 관한 사항
+    pub fn external_valuation(&self) -> ExternalValuation<'_> {
+        ExternalValuation::new(
+            &self.exevl_atn,
+            &self.exevl_bs_rs,
+            &self.exevl_intn,
+            &self.exevl_pd,
+            &self.exevl_op,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de(mock::yyyymmdd())
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build StkExtrDecsnRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bgn_de, mock::yyyymmdd());
+        assert_eq!(params.end_de, "20231231");
+    }
+
+    #[tokio::test]
+    async fn invalid_params_should_fail_before_making_request() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de("invalid")
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build StkExtrDecsnRequestParams");
+
+        let api = OpenDartApi::default();
+        let response = api.get_stk_extr_decsn(params).await;
+        assert!(response.is_err());
+    }
+}
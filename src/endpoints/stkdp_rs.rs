@@ -0,0 +1,188 @@
+//! # 증권예탁증권
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS006&apiId=2020059>
+//!
+//! 증권신고서 내에 증권예탁증권 요약 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::CorpCls;
+use crate::endpoints::de::optional_date;
+use crate::endpoints::identifiers::{CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::registration::{FundUsage, OfferedSecurity, Seller, Underwriter};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use crate::validate::fields::yyyymmdd;
+use chrono::NaiveDate;
+
+impl OpenDartApi {
+    pub async fn get_stkdp_rs(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<StkdpRs>>, OpenDartError> {
+        self.get(self.url("/api/stkdpRs.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub bgn_de: String,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub end_de: String,
+);
+
+json_body!(StkdpRs {
+    pub group: Vec<StkdpRsGroup>,
+});
+
+impl StkdpRs {
+    /// Rows of the 일반사항 section
+    pub fn general(&self) -> &[StkdpRsGeneral] {
+        self.group
+            .iter()
+            .find_map(|group| match group {
+                StkdpRsGroup::General(list) => Some(list.as_slice()),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    /// Rows of the 증권의종류 section
+    pub fn securities(&self) -> &[OfferedSecurity] {
+        self.group
+            .iter()
+            .find_map(|group| match group {
+                StkdpRsGroup::Securities(list) => Some(list.as_slice()),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    /// Rows of the 인수인정보 section
+    pub fn underwriters(&self) -> &[Underwriter] {
+        self.group
+            .iter()
+            .find_map(|group| match group {
+                StkdpRsGroup::Underwriters(list) => Some(list.as_slice()),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    /// Rows of the 자금의사용목적 section
+    pub fn fund_usage(&self) -> &[FundUsage] {
+        self.group
+            .iter()
+            .find_map(|group| match group {
+                StkdpRsGroup::FundUsage(list) => Some(list.as_slice()),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    /// Rows of the 매출인에관한사항 section
+    pub fn sellers(&self) -> &[Seller] {
+        self.group
+            .iter()
+            .find_map(|group| match group {
+                StkdpRsGroup::Sellers(list) => Some(list.as_slice()),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// A section of the summary, identified by its `title`
+#[derive(
+    std::fmt::Debug,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    // serde
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[serde(tag = "title", content = "list")]
+pub enum StkdpRsGroup {
+    /// 일반사항
+    #[serde(rename = "일반사항")]
+    General(Vec<StkdpRsGeneral>),
+    /// 증권의종류
+    #[serde(rename = "증권의종류")]
+    Securities(Vec<OfferedSecurity>),
+    /// 인수인정보
+    #[serde(rename = "인수인정보")]
+    Underwriters(Vec<Underwriter>),
+    /// 자금의사용목적
+    #[serde(rename = "자금의사용목적")]
+    FundUsage(Vec<FundUsage>),
+    /// 매출인에관한사항
+    #[serde(rename = "매출인에관한사항")]
+    Sellers(Vec<Seller>),
+}
+
+derive_common! {
+    StkdpRsGeneral {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 청약기일
+        pub sbd: String,
+        /// 납입기일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub pymd: Reported<NaiveDate>,
+        /// 청약공고일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub sband: Reported<NaiveDate>,
+        /// 배정공고일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub asand: Reported<NaiveDate>,
+        /// 배정기준일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub asstd: Reported<NaiveDate>,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de(mock::yyyymmdd())
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build StkdpRsRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bgn_de, mock::yyyymmdd());
+        assert_eq!(params.end_de, "20231231");
+    }
+
+    #[tokio::test]
+    async fn invalid_params_should_fail_before_making_request() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de("invalid")
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build StkdpRsRequestParams");
+
+        let api = OpenDartApi::default();
+        let response = api.get_stkdp_rs(params).await;
+        assert!(response.is_err());
+    }
+}
@@ -0,0 +1,156 @@
+//! # 주식의 총수 현황
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS002&apiId=2020002>
+//!
+//! 정기보고서(사업, 분기, 반기보고서) 내에 주식의 총수 현황 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::{CorpCls, ReprtCode};
+use crate::endpoints::de::{amount, optional_date};
+use crate::endpoints::identifiers::{BsnsYear, CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::share_class::ShareClass;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use chrono::NaiveDate;
+
+impl OpenDartApi {
+    pub async fn get_stock_totqy_sttus(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<StockTotqySttus>>, OpenDartError> {
+        self.get(self.url("/api/stockTotqySttus.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    pub bsns_year: BsnsYear,
+    pub reprt_code: ReprtCode,
+);
+
+json_body!(StockTotqySttus {
+    pub list: Vec<StockTotqySttusElement>,
+});
+
+impl StockTotqySttus {
+    pub fn get(&self, share_class: &ShareClass) -> Option<&StockTotqySttusElement> {
+        self.list.iter().find(|element| &element.se == share_class)
+    }
+
+    /// 유통주식수 of a share class, e.g. for computing EPS
+    pub fn outstanding(&self, share_class: &ShareClass) -> Option<i64> {
+        self.get(share_class)
+            .and_then(|element| element.distb_stock_co.value())
+    }
+}
+
+derive_common! {
+    StockTotqySttusElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 구분
+        pub se: ShareClass,
+        /// 발행할 주식의 총수
+        #[serde(default, deserialize_with = "amount")]
+        pub isu_stock_totqy: Reported<i64>,
+        /// 현재까지 발행한 주식의 총수
+        #[serde(default, deserialize_with = "amount")]
+        pub now_to_isu_stock_totqy: Reported<i64>,
+        /// 현재까지 감소한 주식의 총수
+        #[serde(default, deserialize_with = "amount")]
+        pub now_to_dcrs_stock_totqy: Reported<i64>,
+        /// 감자
+        #[serde(default, deserialize_with = "amount")]
+        pub redc: Reported<i64>,
+        /// 이익소각
+        #[serde(default, deserialize_with = "amount")]
+        pub profit_incnr: Reported<i64>,
+        /// 상환주식의 상환
+        #[serde(default, deserialize_with = "amount")]
+        pub rdmstk_repy: Reported<i64>,
+        /// 기타
+        #[serde(default, deserialize_with = "amount")]
+        pub etc: Reported<i64>,
+        /// 발행주식의 총수
+        #[serde(default, deserialize_with = "amount")]
+        pub istc_totqy: Reported<i64>,
+        /// 자기주식수
+        #[serde(default, deserialize_with = "amount")]
+        pub tesstk_co: Reported<i64>,
+        /// 유통주식수
+        #[serde(default, deserialize_with = "amount")]
+        pub distb_stock_co: Reported<i64>,
+        /// 결산기준일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub stlm_dt: Reported<NaiveDate>,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+    use serde_json::json;
+
+    fn element(se: &str, distb_stock_co: &str) -> serde_json::Value {
+        json!({
+            "rcept_no": mock::rcept_no(),
+            "corp_cls": "Y",
+            "corp_code": mock::corp_code(),
+            "corp_name": mock::corp_name(),
+            "se": se,
+            "isu_stock_totqy": "25,000,000,000",
+            "now_to_isu_stock_totqy": "7,780,466,850",
+            "now_to_dcrs_stock_totqy": "-",
+            "redc": "-",
+            "profit_incnr": "-",
+            "rdmstk_repy": "-",
+            "etc": "-",
+            "istc_totqy": "5,969,782,550",
+            "tesstk_co": "-",
+            "distb_stock_co": distb_stock_co,
+            "stlm_dt": "2023-12-31",
+        })
+    }
+
+    #[test]
+    fn outstanding_should_find_the_share_class() {
+        let body: StockTotqySttus = serde_json::from_value(json!({
+            "status": "000",
+            "message": "정상",
+            "list": [
+                element("보통주식", "5,969,782,550"),
+                element("우선주", "822,886,700"),
+                element("합계", "6,792,669,250"),
+            ],
+        }))
+        .expect("Failed to deserialize StockTotqySttus");
+
+        assert_eq!(body.outstanding(&ShareClass::Common), Some(5_969_782_550));
+        assert_eq!(body.outstanding(&ShareClass::Preferred), Some(822_886_700));
+        assert!(body.get(&ShareClass::Total).is_some());
+        assert_eq!(body.outstanding(&ShareClass::from("종류주식")), None);
+    }
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bsns_year(mock::bsns_year())
+            .reprt_code(mock::reprt_code())
+            .build()
+            .expect("Failed to build StockTotqySttusRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bsns_year, mock::bsns_year());
+        assert_eq!(params.reprt_code, mock::reprt_code());
+    }
+}
@@ -0,0 +1,149 @@
+//! # 자기주식 취득 및 처분 현황
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS002&apiId=2019006>
+//!
+//! 정기보고서(사업, 분기, 반기보고서) 내에 자기주식 취득 및 처분 현황 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::{CorpCls, ReprtCode};
+use crate::endpoints::de::{amount, optional_date};
+use crate::endpoints::identifiers::{BsnsYear, CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::share_class::ShareClass;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use chrono::NaiveDate;
+
+impl OpenDartApi {
+    pub async fn get_tesstk_acqs_dsps_sttus(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<TesstkAcqsDspsSttus>>, OpenDartError> {
+        self.get(self.url("/api/tesstkAcqsDspsSttus.json"), args)
+            .await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    pub bsns_year: BsnsYear,
+    pub reprt_code: ReprtCode,
+);
+
+json_body!(TesstkAcqsDspsSttus {
+    pub list: Vec<TesstkAcqsDspsSttusElement>,
+});
+
+impl TesstkAcqsDspsSttus {
+    /// Rows of a share class
+    pub fn by_class<'a>(
+        &'a self,
+        share_class: &'a ShareClass,
+    ) -> impl Iterator<Item = &'a TesstkAcqsDspsSttusElement> {
+        self.list
+            .iter()
+            .filter(move |element| &element.stock_knd == share_class)
+    }
+}
+
+derive_common! {
+    TesstkAcqsDspsSttusElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 취득방법 대분류
+        pub acqs_mth1: String,
+        /// 취득방법 중분류
+        pub acqs_mth2: String,
+        /// 취득방법 소분류
+        pub acqs_mth3: String,
+        /// 주식 종류
+        pub stock_knd: ShareClass,
+        /// 기초 수량
+        #[serde(default, deserialize_with = "amount")]
+        pub bsis_qy: Reported<i64>,
+        /// 변동 수량 취득
+        #[serde(default, deserialize_with = "amount")]
+        pub change_qy_acqs: Reported<i64>,
+        /// 변동 수량 처분
+        #[serde(default, deserialize_with = "amount")]
+        pub change_qy_dsps: Reported<i64>,
+        /// 변동 수량 소각
+        #[serde(default, deserialize_with = "amount")]
+        pub change_qy_incnr: Reported<i64>,
+        /// 기말 수량
+        #[serde(default, deserialize_with = "amount")]
+        pub trmend_qy: Reported<i64>,
+        /// 비고
+        pub rm: String,
+        /// 결산기준일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub stlm_dt: Reported<NaiveDate>,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+    use serde_json::json;
+
+    fn element(acqs_mth1: &str, stock_knd: &str) -> serde_json::Value {
+        json!({
+            "rcept_no": mock::rcept_no(),
+            "corp_cls": "Y",
+            "corp_code": mock::corp_code(),
+            "corp_name": mock::corp_name(),
+            "acqs_mth1": acqs_mth1,
+            "acqs_mth2": "직접취득",
+            "acqs_mth3": "장내직접취득",
+            "stock_knd": stock_knd,
+            "bsis_qy": "1,000",
+            "change_qy_acqs": "500",
+            "change_qy_dsps": "-",
+            "change_qy_incnr": "-",
+            "trmend_qy": "1,500",
+            "rm": "-",
+            "stlm_dt": "2023-12-31",
+        })
+    }
+
+    #[test]
+    fn by_class_should_filter_rows_of_the_share_class() {
+        let body: TesstkAcqsDspsSttus = serde_json::from_value(json!({
+            "status": "000",
+            "message": "정상",
+            "list": [
+                element("배당가능이익범위 이내 취득", "보통주"),
+                element("배당가능이익범위 이내 취득", "우선주"),
+                element("총계", "보통주식"),
+            ],
+        }))
+        .expect("Failed to deserialize TesstkAcqsDspsSttus");
+
+        let common = body.by_class(&ShareClass::Common).collect::<Vec<_>>();
+        assert_eq!(common.len(), 2);
+        assert_eq!(common[1].acqs_mth1, "총계");
+        assert_eq!(common[1].trmend_qy.value(), Some(1_500));
+    }
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bsns_year(mock::bsns_year())
+            .reprt_code(mock::reprt_code())
+            .build()
+            .expect("Failed to build TesstkAcqsDspsSttusRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bsns_year, mock::bsns_year());
+        assert_eq!(params.reprt_code, mock::reprt_code());
+    }
+}
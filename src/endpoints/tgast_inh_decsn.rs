@@ -0,0 +1,188 @@
+//! # 유형자산 양수 결정
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS005&apiId=2020045>
+//!
+//! 주요사항보고서 내에 유형자산 양수 결정 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::CorpCls;
+use crate::endpoints::de::{amount, decimal, krw, optional_date};
+use crate::endpoints::identifiers::{CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::transfer::{TransferDecision, TransferDirection};
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use crate::validate::fields::yyyymmdd;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+impl OpenDartApi {
+    pub async fn get_tgast_inh_decsn(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<TgastInhDecsn>>, OpenDartError> {
+        self.get(self.url("/api/tgastInhDecsn.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub bgn_de: String,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub end_de: String,
+);
+
+json_body!(TgastInhDecsn {
+    pub list: Vec<TgastInhDecsnElement>,
+});
+
+derive_common! {
+    TgastInhDecsnElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 자산구분
+        pub ast_sen: String,
+        /// 자산명
+        pub ast_nm: String,
+        /// 양수내역(양수금액(원))
+        #[serde(default, deserialize_with = "krw")]
+        pub inhdtl_inhprc: Reported<i64>,
+        /// 양수내역(자산총액(원))
+        #[serde(default, deserialize_with = "krw")]
+        pub inhdtl_tast: Reported<i64>,
+        /// 양수내역(자산총액대비(%))
+        #[serde(default, deserialize_with = "decimal")]
+        pub inhdtl_tast_vs: Reported<Decimal>,
+        /// 양수목적
+        pub inh_pp: String,
+        /// 양수영향
+        pub inh_af: String,
+        /// 양수예정일자(계약체결일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub inh_prd_ctr_cnsd: Reported<NaiveDate>,
+        /// 양수예정일자(양수기준일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub inh_prd_inh_std: Reported<NaiveDate>,
+        /// 양수예정일자(등기예정일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub inh_prd_rgs_prd: Reported<NaiveDate>,
+        /// 거래상대방(회사명(성명))
+        pub dlptn_cmpnm: String,
+        /// 거래상대방(자본금(원))
+        #[serde(default, deserialize_with = "krw")]
+        pub dlptn_cpt: Reported<i64>,
+        /// 거래상대방(주요사업)
+        pub dlptn_mbsn: String,
+        /// 거래상대방(본점소재지(주소))
+        pub dlptn_hoadd: String,
+        /// 거래상대방(회사와의 관계)
+        pub dlptn_rl_cmpn: String,
+        /// 거래대금지급
+        pub dl_pym: String,
+        /// 외부평가에 관한 사항(외부평가 여부)
+        pub exevl_atn: String,
+        /// 외부평가에 관한 사항(근거 및 사유)
+        pub exevl_bs_rs: String,
+        /// 외부평가에 관한 사항(외부평가기관의 명칭)
+        pub exevl_intn: String,
+        /// 외부평가에 관한 사항(외부평가 기간)
+        pub exevl_pd: String,
+        /// 외부평가에 관한 사항(외부평가 의견)
+        pub exevl_op: String,
+        /// 이사회결의일(결정일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub bddd: Reported<NaiveDate>,
+        /// 사외이사 참석여부(참석)
+        #[serde(default, deserialize_with = "amount")]
+        pub od_a_at_t: Reported<i64>,
+        /// 사외이사 참석여부(불참)
+        #[serde(default, deserialize_with = "amount")]
+        pub od_a_at_b: Reported<i64>,
+        /// 감사(사외이사가 아닌 감사위원) 참석여부
+        pub adt_a_atn: String,
+        /// 풋옵션 등 계약 체결여부
+        pub popt_ctr_atn: String,
+        /// 계약내용
+        pub popt_ctr_cn: String,
+        /// 증권신고서 제출대상 여부
+        pub rs_sm_atn: String,
+        /// 제출을 면제받은 경우 그 사유
+        pub ex_sm_r: String,
+    }
+}
+
+impl TransferDecision for TgastInhDecsnElement {
+    fn rcept_no(&self) -> &str {
+        self.rcept_no.as_str()
+    }
+
+    fn corp_code(&self) -> &str {
+        self.corp_code.as_str()
+    }
+
+    fn direction(&self) -> TransferDirection {
+        TransferDirection::Acquisition
+    }
+
+    fn asset(&self) -> &str {
+        &self.ast_nm
+    }
+
+    fn amount(&self) -> Option<i64> {
+        self.inhdtl_inhprc.value()
+    }
+
+    fn total_assets_ratio(&self) -> Option<Decimal> {
+        self.inhdtl_tast_vs.value()
+    }
+
+    fn counterparty(&self) -> &str {
+        &self.dlptn_cmpnm
+    }
+
+    fn closing_date(&self) -> Option<NaiveDate> {
+        self.inh_prd_inh_std.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de(mock::yyyymmdd())
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build TgastInhDecsnRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bgn_de, mock::yyyymmdd());
+        assert_eq!(params.end_de, "20231231");
+    }
+
+    #[tokio::test]
+    async fn invalid_params_should_fail_before_making_request() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de("invalid")
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build TgastInhDecsnRequestParams");
+
+        let api = OpenDartApi::default();
+        let response = api.get_tgast_inh_decsn(params).await;
+        assert!(response.is_err());
+    }
+}
@@ -0,0 +1,125 @@
+//! A common view of the asset and business transfer decision reports.
+//!
+//! [`TransferDecision`] is implemented by the decision reports of 영업양수도, 유형자산 양수도,
+//! 타법인 주식 및 출자증권 양수도 and 주권 관련 사채권 양수도.
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+/// Whether the company acquires (양수) or disposes of (양도) the asset.
+#[derive(
+    std::fmt::Debug,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    // derive_more
+    derive_more::Display,
+    // serde
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub enum TransferDirection {
+    /// 양수
+    #[display("양수")]
+    Acquisition,
+    /// 양도
+    #[display("양도")]
+    Disposal,
+}
+
+/// A decision to acquire or dispose of a business, an asset, shares or bonds.
+pub trait TransferDecision {
+    fn rcept_no(&self) -> &str;
+
+    fn corp_code(&self) -> &str;
+
+    fn direction(&self) -> TransferDirection;
+
+    /// What is transferred, e.g. the business, the asset or the issuer of the shares
+    fn asset(&self) -> &str;
+
+    /// 양수가액 or 양도가액 (원)
+    fn amount(&self) -> Option<i64>;
+
+    /// [`TransferDecision::amount`] relative to the company's total assets, in percent
+    fn total_assets_ratio(&self) -> Option<Decimal>;
+
+    /// 거래상대방 (회사명 or 성명)
+    fn counterparty(&self) -> &str;
+
+    /// 양수기준일, 양도기준일 or 양수ㆍ양도 예정일자
+    fn closing_date(&self) -> Option<NaiveDate>;
+}
+
+/// Sum of [`TransferDecision::amount`] of the decisions in the given direction.
+///
+/// Decisions without an amount are skipped.
+pub fn total_amount<'a, T: TransferDecision + ?Sized + 'a>(
+    decisions: impl IntoIterator<Item = &'a T>,
+    direction: TransferDirection,
+) -> i64 {
+    decisions
+        .into_iter()
+        .filter(|decision| decision.direction() == direction)
+        .filter_map(|decision| decision.amount())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Decision(TransferDirection, Option<i64>);
+
+    impl TransferDecision for Decision {
+        fn rcept_no(&self) -> &str {
+            "20230512000123"
+        }
+
+        fn corp_code(&self) -> &str {
+            "00126380"
+        }
+
+        fn direction(&self) -> TransferDirection {
+            self.0
+        }
+
+        fn asset(&self) -> &str {
+            "-"
+        }
+
+        fn amount(&self) -> Option<i64> {
+            self.1
+        }
+
+        fn total_assets_ratio(&self) -> Option<Decimal> {
+            None
+        }
+
+        fn counterparty(&self) -> &str {
+            "-"
+        }
+
+        fn closing_date(&self) -> Option<NaiveDate> {
+            None
+        }
+    }
+
+    #[test]
+    fn total_amount_should_sum_decisions_in_direction() {
+        let acquisition = Decision(TransferDirection::Acquisition, Some(1_000));
+        let disposal = Decision(TransferDirection::Disposal, Some(300));
+        let unknown = Decision(TransferDirection::Acquisition, None);
+        let decisions: [&dyn TransferDecision; 3] = [&acquisition, &disposal, &unknown];
+
+        assert_eq!(
+            total_amount(decisions, TransferDirection::Acquisition),
+            1_000
+        );
+        assert_eq!(total_amount(decisions, TransferDirection::Disposal), 300);
+    }
+}
@@ -0,0 +1,175 @@
+//! Pairing of 자기주식취득 신탁계약 체결 and 해지 decisions.
+//!
+//! The signing (`tsstk_aq_trctr_cns_decsn`) and the termination (`tsstk_aq_trctr_cc_decsn`)
+//! of a trust contract are filed as separate reports, which can be matched per company.
+
+use crate::endpoints::tsstk_aq_trctr_cc_decsn::TsstkAqTrctrCcDecsnElement;
+use crate::endpoints::tsstk_aq_trctr_cns_decsn::TsstkAqTrctrCnsDecsnElement;
+
+/// A trust contract for the acquisition of treasury stock.
+///
+/// At least one of `signing` and `termination` is set. `signing` is `None` when the contract
+/// was signed before the period of the given reports, and `termination` is `None`
+/// while the contract hasn't been terminated.
+#[derive(std::fmt::Debug, Clone, Copy, Eq, PartialEq)]
+pub struct TrustContract<'a> {
+    pub signing: Option<&'a TsstkAqTrctrCnsDecsnElement>,
+    pub termination: Option<&'a TsstkAqTrctrCcDecsnElement>,
+}
+
+impl<'a> TrustContract<'a> {
+    pub fn corp_code(&self) -> &'a str {
+        match (self.signing, self.termination) {
+            (Some(signing), _) => signing.corp_code.as_str(),
+            (None, Some(termination)) => termination.corp_code.as_str(),
+            (None, None) => "",
+        }
+    }
+
+    /// `rcept_no` of the first report of the contract
+    fn rcept_no(&self) -> &'a str {
+        match (self.signing, self.termination) {
+            (Some(signing), _) => signing.rcept_no.as_str(),
+            (None, Some(termination)) => termination.rcept_no.as_str(),
+            (None, None) => "",
+        }
+    }
+
+    pub fn is_terminated(&self) -> bool {
+        self.termination.is_some()
+    }
+}
+
+/// Pairs each signing with the termination of the same contract.
+///
+/// A termination is matched to an earlier, still unpaired signing of the same `corp_code`.
+/// A signing whose contract period and amount equal the terminated contract's is preferred;
+/// otherwise the oldest unpaired signing is used.
+///
+/// The contracts are ordered by `corp_code`, then by the `rcept_no` of their first report.
+pub fn trust_contracts<'a>(
+    signings: &'a [TsstkAqTrctrCnsDecsnElement],
+    terminations: &'a [TsstkAqTrctrCcDecsnElement],
+) -> Vec<TrustContract<'a>> {
+    let mut signings = signings.iter().collect::<Vec<_>>();
+    signings.sort_by(|a, b| (&a.corp_code, &a.rcept_no).cmp(&(&b.corp_code, &b.rcept_no)));
+    let mut terminations = terminations.iter().collect::<Vec<_>>();
+    terminations.sort_by(|a, b| (&a.corp_code, &a.rcept_no).cmp(&(&b.corp_code, &b.rcept_no)));
+
+    let mut contracts = signings
+        .into_iter()
+        .map(|signing| TrustContract {
+            signing: Some(signing),
+            termination: None,
+        })
+        .collect::<Vec<_>>();
+
+    for termination in terminations {
+        let candidates = contracts
+            .iter()
+            .enumerate()
+            .filter(|(_, contract)| contract.termination.is_none())
+            .filter_map(|(i, contract)| Some((i, contract.signing?)))
+            .filter(|(_, signing)| {
+                signing.corp_code == termination.corp_code
+                    && signing.rcept_no < termination.rcept_no
+            })
+            .collect::<Vec<_>>();
+        let matched = candidates
+            .iter()
+            .find(|(_, signing)| is_same_contract(signing, termination))
+            .or_else(|| candidates.first())
+            .map(|(i, _)| *i);
+
+        match matched {
+            Some(i) => contracts[i].termination = Some(termination),
+            None => contracts.push(TrustContract {
+                signing: None,
+                termination: Some(termination),
+            }),
+        }
+    }
+
+    contracts.sort_by_key(|contract| (contract.corp_code(), contract.rcept_no()));
+    contracts
+}
+
+fn is_same_contract(
+    signing: &TsstkAqTrctrCnsDecsnElement,
+    termination: &TsstkAqTrctrCcDecsnElement,
+) -> bool {
+    signing.ctr_pd_bgd.value().is_some()
+        && signing.ctr_pd_bgd == termination.ctr_pd_bfcc_bgd
+        && signing.ctr_pd_edd == termination.ctr_pd_bfcc_edd
+        && signing.ctr_prc == termination.ctr_prc_bfcc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+    use serde_json::json;
+
+    fn signing(corp_code: &str, rcept_no: &str, bgd: &str) -> TsstkAqTrctrCnsDecsnElement {
+        serde_json::from_value(json!({
+            "rcept_no": rcept_no,
+            "corp_cls": "Y",
+            "corp_code": corp_code,
+            "corp_name": mock::corp_name(),
+            "ctr_prc": "10,000,000,000",
+            "ctr_pd_bgd": bgd,
+            "ctr_pd_edd": "2024년 12월 31일",
+            "ctr_pp": "주가안정 및 주주가치 제고",
+            "ctr_cns_int": "가나다증권",
+            "adt_a_atn": "-",
+            "cs_iv_bk": "-",
+        }))
+        .expect("Failed to deserialize TsstkAqTrctrCnsDecsnElement")
+    }
+
+    fn termination(corp_code: &str, rcept_no: &str, bgd: &str) -> TsstkAqTrctrCcDecsnElement {
+        serde_json::from_value(json!({
+            "rcept_no": rcept_no,
+            "corp_cls": "Y",
+            "corp_code": corp_code,
+            "corp_name": mock::corp_name(),
+            "ctr_prc_bfcc": "10,000,000,000",
+            "ctr_prc_atcc": "4,000,000,000",
+            "ctr_pd_bfcc_bgd": bgd,
+            "ctr_pd_bfcc_edd": "2024년 12월 31일",
+            "cc_pp": "계약기간 만료 전 해지",
+            "cc_int": "가나다증권",
+            "tp_rm_atcc": "현금 및 주식 반환",
+            "adt_a_atn": "-",
+        }))
+        .expect("Failed to deserialize TsstkAqTrctrCcDecsnElement")
+    }
+
+    #[test]
+    fn trust_contracts_should_pair_signings_with_terminations() {
+        let corp_code = mock::corp_code().to_string();
+        let signings = [
+            signing(&corp_code, "20230301000001", "2023년 03월 02일"),
+            signing(&corp_code, "20230601000001", "2023년 06월 02일"),
+            signing("00164779", "20230401000001", "2023년 04월 03일"),
+        ];
+        let terminations = [
+            termination(&corp_code, "20230901000001", "2023년 06월 02일"),
+            termination("00164779", "20230102000001", "2022년 01월 03일"),
+        ];
+
+        let contracts = trust_contracts(&signings, &terminations);
+        assert_eq!(contracts.len(), 4);
+
+        assert_eq!(contracts[0].corp_code(), corp_code);
+        assert_eq!(contracts[0].signing, Some(&signings[0]));
+        assert!(!contracts[0].is_terminated());
+        assert_eq!(contracts[1].signing, Some(&signings[1]));
+        assert_eq!(contracts[1].termination, Some(&terminations[0]));
+
+        assert_eq!(contracts[2].signing, None);
+        assert_eq!(contracts[2].termination, Some(&terminations[1]));
+        assert_eq!(contracts[3].signing, Some(&signings[2]));
+        assert!(!contracts[3].is_terminated());
+    }
+}
@@ -0,0 +1,142 @@
+//! # 자기주식취득 신탁계약 해지 결정
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS005&apiId=2020042>
+//!
+//! 주요사항보고서 내에 자기주식취득 신탁계약 해지 결정 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::CorpCls;
+use crate::endpoints::de::{amount, decimal, krw, optional_date};
+use crate::endpoints::identifiers::{CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use crate::validate::fields::yyyymmdd;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+impl OpenDartApi {
+    pub async fn get_tsstk_aq_trctr_cc_decsn(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<TsstkAqTrctrCcDecsn>>, OpenDartError> {
+        self.get(self.url("/api/tsstkAqTrctrCcDecsn.json"), args)
+            .await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub bgn_de: String,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub end_de: String,
+);
+
+json_body!(TsstkAqTrctrCcDecsn {
+    pub list: Vec<TsstkAqTrctrCcDecsnElement>,
+});
+
+derive_common! {
+    TsstkAqTrctrCcDecsnElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 계약금액(원)(해지 전)
+        #[serde(default, deserialize_with = "krw")]
+        pub ctr_prc_bfcc: Reported<i64>,
+        /// 계약금액(원)(해지 후)
+        #[serde(default, deserialize_with = "krw")]
+        pub ctr_prc_atcc: Reported<i64>,
+        /// 해지 전 계약기간(시작일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub ctr_pd_bfcc_bgd: Reported<NaiveDate>,
+        /// 해지 전 계약기간(종료일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub ctr_pd_bfcc_edd: Reported<NaiveDate>,
+        /// 해지목적
+        pub cc_pp: String,
+        /// 해지기관
+        pub cc_int: String,
+        /// 해지예정일자
+        #[serde(default, deserialize_with = "optional_date")]
+        pub cc_prd: Reported<NaiveDate>,
+        /// 해지후 신탁재산의 반환방법
+        pub tp_rm_atcc: String,
+        /// 해지 전 자기주식 보유현황(배당가능이익 범위 내 취득(주)(보통주식))
+        #[serde(default, deserialize_with = "amount")]
+        pub aq_wtn_div_ostk: Reported<i64>,
+        /// 해지 전 자기주식 보유현황(배당가능이익 범위 내 취득(주)(비율(%)))
+        #[serde(default, deserialize_with = "decimal")]
+        pub aq_wtn_div_ostk_rt: Reported<Decimal>,
+        /// 해지 전 자기주식 보유현황(배당가능이익 범위 내 취득(주)(기타주식))
+        #[serde(default, deserialize_with = "amount")]
+        pub aq_wtn_div_estk: Reported<i64>,
+        /// 해지 전 자기주식 보유현황(배당가능이익 범위 내 취득(주)(비율(%)))
+        #[serde(default, deserialize_with = "decimal")]
+        pub aq_wtn_div_estk_rt: Reported<Decimal>,
+        /// 해지 전 자기주식 보유현황(기타취득(주)(보통주식))
+        #[serde(default, deserialize_with = "amount")]
+        pub eaq_ostk: Reported<i64>,
+        /// 해지 전 자기주식 보유현황(기타취득(주)(비율(%)))
+        #[serde(default, deserialize_with = "decimal")]
+        pub eaq_ostk_rt: Reported<Decimal>,
+        /// 해지 전 자기주식 보유현황(기타취득(주)(기타주식))
+        #[serde(default, deserialize_with = "amount")]
+        pub eaq_estk: Reported<i64>,
+        /// 해지 전 자기주식 보유현황(기타취득(주)(비율(%)))
+        #[serde(default, deserialize_with = "decimal")]
+        pub eaq_estk_rt: Reported<Decimal>,
+        /// 이사회결의일(결정일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub bddd: Reported<NaiveDate>,
+        /// 사외이사 참석여부(참석)
+        #[serde(default, deserialize_with = "amount")]
+        pub od_a_at_t: Reported<i64>,
+        /// 사외이사 참석여부(불참)
+        #[serde(default, deserialize_with = "amount")]
+        pub od_a_at_b: Reported<i64>,
+        /// 감사(사외이사가 아닌 감사위원) 참석여부
+        pub adt_a_atn: String,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de(mock::yyyymmdd())
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build TsstkAqTrctrCcDecsnRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bgn_de, mock::yyyymmdd());
+        assert_eq!(params.end_de, "20231231");
+    }
+
+    #[tokio::test]
+    async fn invalid_params_should_fail_before_making_request() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de("invalid")
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build TsstkAqTrctrCcDecsnRequestParams");
+
+        let api = OpenDartApi::default();
+        let response = api.get_tsstk_aq_trctr_cc_decsn(params).await;
+        assert!(response.is_err());
+    }
+}
@@ -0,0 +1,166 @@
+//! # 자기주식 처분 결정
+//! <https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS005&apiId=2020040>
+//!
+//! 주요사항보고서 내에 자기주식 처분 결정 정보를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::codes::CorpCls;
+use crate::endpoints::de::{amount, decimal, krw, optional_date};
+use crate::endpoints::identifiers::{CorpCode, RceptNo};
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::reported::Reported;
+use crate::endpoints::OpenDartResponse;
+use crate::error::OpenDartError;
+use crate::validate::fields::yyyymmdd;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+impl OpenDartApi {
+    pub async fn get_tsstk_dp_decsn(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<TsstkDpDecsn>>, OpenDartError> {
+        self.get(self.url("/api/tsstkDpDecsn.json"), args).await
+    }
+}
+
+params!(
+    pub corp_code: CorpCode,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub bgn_de: String,
+    #[validate(custom(function = "yyyymmdd"))]
+    pub end_de: String,
+);
+
+json_body!(TsstkDpDecsn {
+    pub list: Vec<TsstkDpDecsnElement>,
+});
+
+derive_common! {
+    TsstkDpDecsnElement {
+        /// 접수번호
+        pub rcept_no: RceptNo,
+        /// 법인구분
+        pub corp_cls: CorpCls,
+        /// 고유번호
+        pub corp_code: CorpCode,
+        /// 회사명
+        pub corp_name: String,
+        /// 처분예정주식(주)(보통주식)
+        #[serde(default, deserialize_with = "amount")]
+        pub dppln_stk_ostk: Reported<i64>,
+        /// 처분예정주식(주)(기타주식)
+        #[serde(default, deserialize_with = "amount")]
+        pub dppln_stk_estk: Reported<i64>,
+        /// 처분 대상 주식가격(원)(보통주식)
+        #[serde(default, deserialize_with = "krw")]
+        pub dpstk_prc_ostk: Reported<i64>,
+        /// 처분 대상 주식가격(원)(기타주식)
+        #[serde(default, deserialize_with = "krw")]
+        pub dpstk_prc_estk: Reported<i64>,
+        /// 처분예정금액(원)(보통주식)
+        #[serde(default, deserialize_with = "krw")]
+        pub dppln_prc_ostk: Reported<i64>,
+        /// 처분예정금액(원)(기타주식)
+        #[serde(default, deserialize_with = "krw")]
+        pub dppln_prc_estk: Reported<i64>,
+        /// 처분예정기간(시작일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub dpprpd_bgd: Reported<NaiveDate>,
+        /// 처분예정기간(종료일)
+        #[serde(default, deserialize_with = "optional_date")]
+        pub dpprpd_edd: Reported<NaiveDate>,
+        /// 처분목적
+        pub dp_pp: String,
+        /// 처분방법(시장을 통한 매도(주))
+        #[serde(default, deserialize_with = "amount")]
+        pub dp_m_mkt: Reported<i64>,
+        /// 처분방법(시간외대량매매(주))
+        #[serde(default, deserialize_with = "amount")]
+        pub dp_m_ovtm: Reported<i64>,
+        /// 처분방법(장외처분(주))
+        #[serde(default, deserialize_with = "amount")]
+        pub dp_m_otc: Reported<i64>,
+        /// 처분방법(기타(주))
+        #[serde(default, deserialize_with = "amount")]
+        pub dp_m_etc: Reported<i64>,
+        /// 위탁투자중개업자
+        pub cs_iv_bk: String,
+        /// 처분 전 자기주식 보유현황(배당가능이익 범위 내 취득(주)(보통주식))
+        #[serde(default, deserialize_with = "amount")]
+        pub aq_wtn_div_ostk: Reported<i64>,
+        /// 처분 전 자기주식 보유현황(배당가능이익 범위 내 취득(주)(비율(%)))
+        #[serde(default, deserialize_with = "decimal")]
+        pub aq_wtn_div_ostk_rt: Reported<Decimal>,
+        /// 처분 전 자기주식 보유현황(배당가능이익 범위 내 취득(주)(기타주식))
+        #[serde(default, deserialize_with = "amount")]
+        pub aq_wtn_div_estk: Reported<i64>,
+        /// 처분 전 자기주식 보유현황(배당가능이익 범위 내 취득(주)(비율(%)))
+        #[serde(default, deserialize_with = "decimal")]
+        pub aq_wtn_div_estk_rt: Reported<Decimal>,
+        /// 처분 전 자기주식 보유현황(기타취득(주)(보통주식))
+        #[serde(default, deserialize_with = "amount")]
+        pub eaq_ostk: Reported<i64>,
+        /// 처분 전 자기주식 보유현황(기타취득(주)(비율(%)))
+        #[serde(default, deserialize_with = "decimal")]
+        pub eaq_ostk_rt: Reported<Decimal>,
+        /// 처분 전 자기주식 보유현황(기타취득(주)(기타주식))
+        #[serde(default, deserialize_with = "amount")]
+        pub eaq_estk: Reported<i64>,
+        /// 처분 전 자기주식 보유현황(기타취득(주)(비율(%)))
+        #[serde(default, deserialize_with = "decimal")]
+        pub eaq_estk_rt: Reported<Decimal>,
+        /// 처분결정일
+        #[serde(default, deserialize_with = "optional_date")]
+        pub dp_dd: Reported<NaiveDate>,
+        /// 사외이사 참석여부(참석)
+        #[serde(default, deserialize_with = "amount")]
+        pub od_a_at_t: Reported<i64>,
+        /// 사외이사 참석여부(불참)
+        #[serde(default, deserialize_with = "amount")]
+        pub od_a_at_b: Reported<i64>,
+        /// 감사(사외이사가 아닌 감사위원) 참석여부
+        pub adt_a_atn: String,
+        /// 1일 매도 주문수량 한도(보통주식)
+        #[serde(default, deserialize_with = "amount")]
+        pub d1_slodlm_ostk: Reported<i64>,
+        /// 1일 매도 주문수량 한도(기타주식)
+        #[serde(default, deserialize_with = "amount")]
+        pub d1_slodlm_estk: Reported<i64>,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de(mock::yyyymmdd())
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build TsstkDpDecsnRequestParams");
+
+        assert_eq!(params.corp_code, mock::corp_code());
+        assert_eq!(params.bgn_de, mock::yyyymmdd());
+        assert_eq!(params.end_de, "20231231");
+    }
+
+    #[tokio::test]
+    async fn invalid_params_should_fail_before_making_request() {
+        let params = ParamsBuilder::default()
+            .corp_code(mock::corp_code())
+            .bgn_de("invalid")
+            .end_de("20231231")
+            .build()
+            .expect("Failed to build TsstkDpDecsnRequestParams");
+
+        let api = OpenDartApi::default();
+        let response = api.get_tsstk_dp_decsn(params).await;
+        assert!(response.is_err());
+    }
+}
@@ -0,0 +1,563 @@
+//! ## XBRL택사노미재무제표양식 개발가이드
+//! [link](https://opendart.fss.or.kr/guide/detail.do?apiGrpCd=DS003&apiId=2020001)
+//! 금융감독원 회계포탈에서 제공하는 IFRS 기반 XBRL 재무제표 공시용 표준계정과목체계(계정과목) 를 제공합니다.
+
+use crate::client::OpenDartApi;
+use crate::endpoints::base::ResponseBody;
+use crate::endpoints::macros::{derive_common, json_body, params};
+use crate::endpoints::OpenDartResponse;
+use crate::validate::fields::sj_div;
+use crate::OpenDartError;
+use std::collections::{BTreeMap, BTreeSet};
+
+impl OpenDartApi {
+    pub async fn get_xbrl_taxonomy(
+        &self,
+        args: Params,
+    ) -> Result<OpenDartResponse<ResponseBody<XbrlTaxonomy>>, OpenDartError> {
+        self.get(self.url("/api/xbrlTaxonomy.json"), args).await
+    }
+}
+
+params!(
+    #[validate(custom(function = "sj_div"))]
+    pub sj_div: String,
+);
+
+json_body!(XbrlTaxonomy {
+    pub list: Vec<XbrlTaxonomyElement>,
+});
+
+derive_common! {
+    XbrlTaxonomyElement {
+        pub sj_div: String,
+        pub account_id: String,
+        pub account_nm: String,
+        pub bsns_de: String,
+        pub label_kor: String,
+        pub label_eng: Option<String>,
+        pub data_tp: Option<String>,
+        pub ifrs_ref: Option<String>,
+    }
+}
+
+impl XbrlTaxonomyElement {
+    pub fn data_type(&self) -> DataType {
+        DataType::from(self.data_tp.as_deref().unwrap_or_default())
+    }
+
+    /// Abstract elements are headings which group the accounts following them
+    /// and do not carry values themselves.
+    pub fn is_abstract(&self) -> bool {
+        self.account_id.ends_with("Abstract")
+    }
+}
+
+/// ## 데이터 유형
+#[derive(
+    std::fmt::Debug,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    // derive_more
+    derive_more::Display,
+    // serde
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[display("{self:?}")]
+pub enum DataType {
+    /// text block: 제목
+    TextBlock,
+    /// Text: Text
+    Text,
+    /// yyyy-mm-dd: Date
+    Date,
+    /// X: Monetary Value
+    Monetary,
+    /// (X): Monetary Value(Negative)
+    NegativeMonetary,
+    /// X.XX: Decimalized Value
+    Decimal,
+    /// Shares: Number of shares (주식 수)
+    Shares,
+    /// For each: 공시된 항목이 전후로 반복적으로 공시될 경우 사용
+    ForEach,
+    /// 공란: 입력 필요 없음
+    Empty,
+    Other(String),
+}
+
+impl From<&str> for DataType {
+    fn from(value: &str) -> Self {
+        match value.trim() {
+            "text block" => DataType::TextBlock,
+            "Text" => DataType::Text,
+            "yyyy-mm-dd" => DataType::Date,
+            "X" => DataType::Monetary,
+            "(X)" => DataType::NegativeMonetary,
+            "X.XX" => DataType::Decimal,
+            "Shares" => DataType::Shares,
+            "For each" => DataType::ForEach,
+            "" => DataType::Empty,
+            other => DataType::Other(other.to_string()),
+        }
+    }
+}
+
+// region: Taxonomy index
+
+/// An in-memory index over the taxonomy of one or more `sj_div`s,
+/// for resolving the `account_id`s returned by the financial statement endpoints.
+///
+/// ```no_run
+/// # async fn run() -> Result<(), open_dart::OpenDartError> {
+/// use open_dart::client::OpenDartApi;
+/// use open_dart::endpoints::xbrl_taxonomy::{ParamsBuilder, Taxonomy};
+///
+/// let api = OpenDartApi::default();
+/// let mut taxonomy = Taxonomy::default();
+/// for sj_div in ["BS1", "IS1"] {
+///     let params = ParamsBuilder::default().sj_div(sj_div).build()?;
+///     let response = api.get_xbrl_taxonomy(params).await?;
+///     if let Some(open_dart::endpoints::ResponseBody::Body(body)) = response.body {
+///         taxonomy.extend(body.list);
+///     }
+/// }
+///
+/// if let Some(account) = taxonomy.lookup("ifrs-full_Revenue") {
+///     println!("{} / {:?}", account.element.label_kor, account.element.label_eng);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(std::fmt::Debug, Clone, Default, Eq, PartialEq)]
+pub struct Taxonomy {
+    /// Elements grouped by `sj_div`, in the order they are presented in the statement
+    statements: BTreeMap<String, Vec<XbrlTaxonomyElement>>,
+    /// `account_id` to (`sj_div`, position) pairs
+    accounts: BTreeMap<String, Vec<(String, usize)>>,
+    /// Position of the heading each element is presented under, parallel to `statements`
+    parents: BTreeMap<String, Vec<Option<usize>>>,
+}
+
+/// An account resolved from a [`Taxonomy`], with its position in the statement.
+#[derive(std::fmt::Debug, Clone, Copy, Eq, PartialEq)]
+pub struct TaxonomyAccount<'a> {
+    pub element: &'a XbrlTaxonomyElement,
+    /// Zero-based position of the account in the statement of its `sj_div`
+    pub position: usize,
+    /// The abstract heading the account is presented under, if any
+    pub parent: Option<&'a XbrlTaxonomyElement>,
+    /// Number of abstract headings the account is nested in
+    pub depth: usize,
+}
+
+impl Taxonomy {
+    pub fn new<I>(elements: I) -> Self
+    where
+        I: IntoIterator<Item = XbrlTaxonomyElement>,
+    {
+        let mut taxonomy = Self::default();
+        taxonomy.extend(elements);
+        taxonomy
+    }
+
+    /// Adds elements to the index, keeping their order within each `sj_div`.
+    pub fn extend<I>(&mut self, elements: I)
+    where
+        I: IntoIterator<Item = XbrlTaxonomyElement>,
+    {
+        let mut extended = BTreeSet::new();
+        for element in elements {
+            let statement = self.statements.entry(element.sj_div.clone()).or_default();
+            self.accounts
+                .entry(element.account_id.clone())
+                .or_default()
+                .push((element.sj_div.clone(), statement.len()));
+            extended.insert(element.sj_div.clone());
+            statement.push(element);
+        }
+
+        for sj_div in extended {
+            let parents = parents(self.statement(&sj_div));
+            self.parents.insert(sj_div, parents);
+        }
+    }
+
+    /// Resolves an `account_id` from the first statement in which it appears.
+    pub fn lookup(&self, account_id: &str) -> Option<TaxonomyAccount<'_>> {
+        self.lookup_all(account_id).next()
+    }
+
+    /// Resolves an `account_id` within the statement of the given `sj_div`.
+    pub fn lookup_in(&self, sj_div: &str, account_id: &str) -> Option<TaxonomyAccount<'_>> {
+        self.lookup_all(account_id)
+            .find(|account| account.element.sj_div == sj_div)
+    }
+
+    /// Resolves an `account_id` from every statement in which it appears.
+    pub fn lookup_all<'a>(
+        &'a self,
+        account_id: &str,
+    ) -> impl Iterator<Item = TaxonomyAccount<'a>> + 'a {
+        self.accounts
+            .get(account_id)
+            .into_iter()
+            .flatten()
+            .filter_map(|(sj_div, position)| self.account(sj_div, *position))
+    }
+
+    /// Elements of the statement of the given `sj_div`, in presentation order.
+    pub fn statement(&self, sj_div: &str) -> &[XbrlTaxonomyElement] {
+        self.statements.get(sj_div).map_or(&[], Vec::as_slice)
+    }
+
+    /// Accounts and nested headings directly under the given abstract heading.
+    pub fn children<'a>(
+        &'a self,
+        parent: &TaxonomyAccount<'a>,
+    ) -> impl Iterator<Item = &'a XbrlTaxonomyElement> + 'a {
+        let sj_div = parent.element.sj_div.as_str();
+        let position = parent.position;
+        self.statement(sj_div)
+            .iter()
+            .zip(self.parents.get(sj_div).into_iter().flatten())
+            .filter(move |(_, element_parent)| **element_parent == Some(position))
+            .map(|(element, _)| element)
+    }
+
+    pub fn len(&self) -> usize {
+        self.statements.values().map(Vec::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn account(&self, sj_div: &str, position: usize) -> Option<TaxonomyAccount<'_>> {
+        let statement = self.statements.get(sj_div)?;
+        let element = statement.get(position)?;
+        let parents = self.parents.get(sj_div)?;
+        let depth = std::iter::successors(parents[position], |parent| parents[*parent]).count();
+
+        Some(TaxonomyAccount {
+            element,
+            position,
+            parent: parents[position].map(|parent| &statement[parent]),
+            depth,
+        })
+    }
+}
+
+/// Position of the heading each element of a statement is presented under.
+///
+/// The taxonomy has no level or parent column, so the nesting is inferred from the order of the elements:
+/// - a heading directly following another heading is nested in it;
+/// - a total, whose `account_id` is the one of an open heading without the `Abstract` suffix
+///   (e.g. `ifrs-full_Assets` for `ifrs-full_AssetsAbstract`),
+///   closes that heading and every heading nested in it;
+/// - any other heading following accounts closes only the innermost heading.
+///
+/// For example, `부채 [abstract]` is under `재무상태표 [abstract]` once `자산총계` has closed `자산 [abstract]`,
+/// but a statement which omits the totals nests every heading following accounts one level too deep.
+fn parents(statement: &[XbrlTaxonomyElement]) -> Vec<Option<usize>> {
+    let mut parents = Vec::with_capacity(statement.len());
+    let mut headings: Vec<usize> = Vec::new();
+    let mut section_open = false;
+
+    for (position, element) in statement.iter().enumerate() {
+        if element.is_abstract() {
+            if section_open {
+                headings.pop();
+            }
+            parents.push(headings.last().copied());
+            headings.push(position);
+            section_open = false;
+            continue;
+        }
+
+        let closed = headings.iter().rposition(|heading| {
+            statement[*heading].account_id.strip_suffix("Abstract")
+                == Some(element.account_id.as_str())
+        });
+        match closed {
+            Some(index) => {
+                parents.push(Some(headings[index]));
+                headings.truncate(index);
+                section_open = false;
+            }
+            None => {
+                parents.push(headings.last().copied());
+                section_open = true;
+            }
+        }
+    }
+    parents
+}
+
+impl FromIterator<XbrlTaxonomyElement> for Taxonomy {
+    fn from_iter<I: IntoIterator<Item = XbrlTaxonomyElement>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+// endregion: Taxonomy index
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::mock;
+
+    fn element(
+        sj_div: &str,
+        account_id: &str,
+        label_kor: &str,
+        data_tp: &str,
+    ) -> XbrlTaxonomyElement {
+        XbrlTaxonomyElement {
+            sj_div: sj_div.to_string(),
+            account_id: account_id.to_string(),
+            account_nm: label_kor.to_string(),
+            bsns_de: "20230630".to_string(),
+            label_kor: label_kor.to_string(),
+            label_eng: None,
+            data_tp: Some(data_tp.to_string()),
+            ifrs_ref: None,
+        }
+    }
+
+    #[test]
+    fn taxonomy_should_resolve_accounts_with_their_position() {
+        let taxonomy = Taxonomy::new([
+            element(
+                "IS1",
+                "ifrs-full_IncomeStatementAbstract",
+                "손익계산서 [abstract]",
+                "text block",
+            ),
+            element("IS1", "ifrs-full_Revenue", "수익(매출액)", "X"),
+            element("IS1", "ifrs-full_CostOfSales", "매출원가", "(X)"),
+            element("IS1", "dart_OperatingIncomeLoss", "영업이익(손실)", "X"),
+            element(
+                "IS3",
+                "ifrs-full_IncomeStatementAbstract",
+                "손익계산서 [abstract]",
+                "text block",
+            ),
+            element("IS3", "ifrs-full_Revenue", "수익(매출액)", "X"),
+        ]);
+
+        assert_eq!(taxonomy.len(), 6);
+
+        let revenue = taxonomy.lookup("ifrs-full_Revenue").unwrap();
+        assert_eq!(revenue.element.sj_div, "IS1");
+        assert_eq!(revenue.position, 1);
+        assert_eq!(revenue.element.data_type(), DataType::Monetary);
+        assert_eq!(
+            revenue.parent.map(|parent| parent.account_id.as_str()),
+            Some("ifrs-full_IncomeStatementAbstract")
+        );
+        assert_eq!(taxonomy.lookup_all("ifrs-full_Revenue").count(), 2);
+        assert_eq!(
+            taxonomy
+                .lookup_in("IS3", "ifrs-full_Revenue")
+                .map(|account| account.position),
+            Some(1)
+        );
+
+        let cost_of_sales = taxonomy.lookup("ifrs-full_CostOfSales").unwrap();
+        assert_eq!(
+            cost_of_sales.element.data_type(),
+            DataType::NegativeMonetary
+        );
+
+        let heading = taxonomy
+            .lookup_in("IS1", "ifrs-full_IncomeStatementAbstract")
+            .unwrap();
+        assert!(heading.parent.is_none());
+        assert_eq!(heading.depth, 0);
+        assert_eq!(revenue.depth, 1);
+        assert_eq!(taxonomy.children(&heading).count(), 3);
+
+        assert!(taxonomy.lookup("ifrs-full_Unknown").is_none());
+    }
+
+    #[test]
+    fn taxonomy_should_nest_headings() {
+        let taxonomy = Taxonomy::new([
+            element(
+                "BS1",
+                "ifrs-full_StatementOfFinancialPositionAbstract",
+                "재무상태표 [abstract]",
+                "text block",
+            ),
+            element(
+                "BS1",
+                "ifrs-full_AssetsAbstract",
+                "자산 [abstract]",
+                "text block",
+            ),
+            element("BS1", "ifrs-full_CurrentAssets", "유동자산", "X"),
+            element(
+                "BS1",
+                "ifrs-full_CashAndCashEquivalents",
+                "현금및현금성자산",
+                "X",
+            ),
+            element("BS1", "ifrs-full_Assets", "자산총계", "X"),
+            element(
+                "BS1",
+                "ifrs-full_LiabilitiesAbstract",
+                "부채 [abstract]",
+                "text block",
+            ),
+            element("BS1", "ifrs-full_CurrentLiabilities", "유동부채", "X"),
+            element("BS1", "ifrs-full_Liabilities", "부채총계", "X"),
+        ]);
+
+        let statement = taxonomy
+            .lookup("ifrs-full_StatementOfFinancialPositionAbstract")
+            .unwrap();
+        let sections = taxonomy
+            .children(&statement)
+            .map(|element| element.account_id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            sections,
+            ["ifrs-full_AssetsAbstract", "ifrs-full_LiabilitiesAbstract"]
+        );
+
+        let assets = taxonomy.lookup("ifrs-full_AssetsAbstract").unwrap();
+        assert_eq!(assets.depth, 1);
+        assert_eq!(taxonomy.children(&assets).count(), 3);
+
+        let liabilities = taxonomy.lookup("ifrs-full_LiabilitiesAbstract").unwrap();
+        assert_eq!(
+            liabilities.parent.map(|parent| parent.account_id.as_str()),
+            Some("ifrs-full_StatementOfFinancialPositionAbstract")
+        );
+        assert_eq!(taxonomy.children(&liabilities).count(), 2);
+
+        let cash = taxonomy.lookup("ifrs-full_CashAndCashEquivalents").unwrap();
+        assert_eq!(cash.depth, 2);
+        assert_eq!(
+            cash.parent.map(|parent| parent.account_id.as_str()),
+            Some("ifrs-full_AssetsAbstract")
+        );
+    }
+
+    #[test]
+    fn taxonomy_should_close_nested_headings_at_their_totals() {
+        let heading =
+            |account_id: &str, label_kor: &str| element("BS1", account_id, label_kor, "text block");
+        let account =
+            |account_id: &str, label_kor: &str| element("BS1", account_id, label_kor, "X");
+        let taxonomy = Taxonomy::new([
+            heading(
+                "ifrs-full_StatementOfFinancialPositionAbstract",
+                "재무상태표 [abstract]",
+            ),
+            heading("ifrs-full_AssetsAbstract", "자산 [abstract]"),
+            heading("ifrs-full_CurrentAssetsAbstract", "유동자산 [abstract]"),
+            account("ifrs-full_CashAndCashEquivalents", "현금및현금성자산"),
+            account("ifrs-full_CurrentAssets", "유동자산 합계"),
+            heading(
+                "ifrs-full_NoncurrentAssetsAbstract",
+                "비유동자산 [abstract]",
+            ),
+            account("ifrs-full_PropertyPlantAndEquipment", "유형자산"),
+            account("ifrs-full_NoncurrentAssets", "비유동자산 합계"),
+            account("ifrs-full_Assets", "자산총계"),
+            heading("ifrs-full_LiabilitiesAbstract", "부채 [abstract]"),
+            account("ifrs-full_Liabilities", "부채총계"),
+        ]);
+        let parent_of = |account_id: &str| {
+            taxonomy
+                .lookup(account_id)
+                .and_then(|account| account.parent)
+                .map(|parent| parent.account_id.as_str())
+        };
+
+        assert_eq!(
+            parent_of("ifrs-full_CurrentAssetsAbstract"),
+            Some("ifrs-full_AssetsAbstract")
+        );
+        assert_eq!(
+            parent_of("ifrs-full_CurrentAssets"),
+            Some("ifrs-full_CurrentAssetsAbstract")
+        );
+        assert_eq!(
+            parent_of("ifrs-full_NoncurrentAssetsAbstract"),
+            Some("ifrs-full_AssetsAbstract")
+        );
+        assert_eq!(
+            parent_of("ifrs-full_Assets"),
+            Some("ifrs-full_AssetsAbstract")
+        );
+        assert_eq!(
+            parent_of("ifrs-full_LiabilitiesAbstract"),
+            Some("ifrs-full_StatementOfFinancialPositionAbstract")
+        );
+
+        let cash = taxonomy.lookup("ifrs-full_CashAndCashEquivalents").unwrap();
+        assert_eq!(cash.depth, 3);
+
+        let assets = taxonomy.lookup("ifrs-full_AssetsAbstract").unwrap();
+        let sections = taxonomy
+            .children(&assets)
+            .map(|element| element.account_id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            sections,
+            [
+                "ifrs-full_CurrentAssetsAbstract",
+                "ifrs-full_NoncurrentAssetsAbstract",
+                "ifrs-full_Assets"
+            ]
+        );
+    }
+
+    #[test]
+    fn taxonomy_should_keep_parents_when_extended() {
+        let mut taxonomy = Taxonomy::new([element(
+            "IS1",
+            "ifrs-full_IncomeStatementAbstract",
+            "손익계산서 [abstract]",
+            "text block",
+        )]);
+        taxonomy.extend([element("IS1", "ifrs-full_Revenue", "수익(매출액)", "X")]);
+
+        let revenue = taxonomy.lookup("ifrs-full_Revenue").unwrap();
+        assert_eq!(revenue.depth, 1);
+        assert_eq!(
+            revenue.parent.map(|parent| parent.account_id.as_str()),
+            Some("ifrs-full_IncomeStatementAbstract")
+        );
+    }
+
+    #[test]
+    fn params_builder_works_with_all_fields_specified() {
+        let params = ParamsBuilder::default()
+            .sj_div(mock::sj_div())
+            .build()
+            .expect("Failed to build XbrlTaxonomyRequestParams");
+
+        assert_eq!(params.sj_div, mock::sj_div());
+    }
+
+    #[tokio::test]
+    async fn invalid_params_should_fail_before_making_request() {
+        let params = ParamsBuilder::default()
+            .sj_div("invalid")
+            .build()
+            .expect("Failed to build XbrlTaxonomyRequestParams");
+
+        let api = OpenDartApi::default();
+        let response = api.get_xbrl_taxonomy(params).await;
+        assert!(response.is_err());
+    }
+}
@@ -23,6 +23,8 @@ pub enum OpenDartError {
     Message(#[from] MessageError),
     #[error("quick_xml error: {0}")]
     QuickXml(#[from] quick_xml::errors::Error),
+    #[error("quick_xml deserialization error: {0}")]
+    QuickXmlDeserialize(#[from] quick_xml::DeError),
     #[error("response error: {0}")]
     Response(#[from] ResponseError),
     #[error("unexpected zip content error: {0}")]
@@ -102,3 +104,69 @@ pub struct ResponseError {
 pub struct UnexpectedZipContentError {
     pub files: Vec<String>,
 }
+
+#[derive(
+    std::fmt::Debug,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    // derive_more
+    derive_more::From,
+    derive_more::Into,
+    // serde
+    serde::Serialize,
+    serde::Deserialize,
+    // thiserror
+    Error,
+)]
+#[error("{self:?}")]
+pub struct ParseTenureError {
+    pub value: String,
+}
+
+#[derive(
+    std::fmt::Debug,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    // derive_more
+    derive_more::From,
+    derive_more::Into,
+    // serde
+    serde::Serialize,
+    serde::Deserialize,
+    // thiserror
+    Error,
+)]
+#[error("{self:?}")]
+pub struct ParseRatioError {
+    pub value: String,
+}
+
+#[derive(
+    std::fmt::Debug,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    // derive_more
+    derive_more::From,
+    derive_more::Into,
+    // serde
+    serde::Serialize,
+    serde::Deserialize,
+    // thiserror
+    Error,
+)]
+#[error("{self:?}")]
+pub struct ParseCodeError {
+    pub value: String,
+}
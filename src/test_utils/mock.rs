@@ -1,45 +1,56 @@
+use crate::endpoints::codes::{CorpCls, FsDiv, PblntfDetailTy, PblntfTy, ReprtCode, Sort, SortMth};
+use crate::endpoints::identifiers::{BsnsYear, CorpCode, RceptNo};
+
 pub(crate) fn yyyymmdd() -> String {
     "20230101".to_string()
 }
 
-pub(crate) fn bsns_year() -> String {
-    "2023".to_string()
+pub(crate) fn bsns_year() -> BsnsYear {
+    BsnsYear::new("2023").expect("The mock bsns_year should be valid")
 }
 
-pub(crate) fn corp_cls() -> String {
-    "Y".to_string()
+pub(crate) fn corp_cls() -> CorpCls {
+    CorpCls::Kospi
 }
 
-pub(crate) fn corp_code() -> String {
-    "00126380".to_string()
+pub(crate) fn corp_code() -> CorpCode {
+    CorpCode::new("00126380").expect("The mock corp_code should be valid")
 }
 
 pub(crate) fn corp_name() -> String {
     "삼성전자(주)".to_string()
 }
 
-pub(crate) fn pblntf_ty() -> String {
-    "F".to_string()
+pub(crate) fn pblntf_ty() -> PblntfTy {
+    PblntfTy::ExternalAudit
+}
+
+pub(crate) fn pbntf_detail_ty() -> PblntfDetailTy {
+    PblntfDetailTy::F001
+}
+
+pub(crate) fn fs_div() -> FsDiv {
+    FsDiv::Consolidated
 }
 
-pub(crate) fn pbntf_detail_ty() -> String {
-    "F001".to_string()
+pub(crate) fn rcept_no() -> RceptNo {
+    RceptNo::new("20240312000736").expect("The mock rcept_no should be valid")
 }
 
-pub(crate) fn fs_div() -> String {
-    "CFS".to_string()
+pub(crate) fn reprt_code() -> ReprtCode {
+    ReprtCode::Annual
 }
 
-pub(crate) fn reprt_code() -> String {
-    "11011".to_string()
+pub(crate) fn sj_div() -> String {
+    "BS1".to_string()
 }
 
-pub(crate) fn sort() -> String {
-    "date".to_string()
+pub(crate) fn sort() -> Sort {
+    Sort::Date
 }
 
-pub(crate) fn sort_mth() -> String {
-    "desc".to_string()
+pub(crate) fn sort_mth() -> SortMth {
+    SortMth::Desc
 }
 
 pub(crate) fn yes_no() -> String {
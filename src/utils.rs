@@ -1,4 +1,117 @@
 macro_rules! derive_newtype {
+    (
+        #[validate(custom(function = $validate:path))]
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident(String);
+    ) => {
+        #[derive(
+            std::fmt::Debug,
+            Clone,
+            Eq,
+            PartialEq,
+            Ord,
+            PartialOrd,
+            Hash,
+            // derive_more
+            derive_more::AsRef,
+            derive_more::Display,
+            derive_more::Into,
+            // serde
+            serde::Serialize,
+            serde::Deserialize,
+        )]
+        #[cfg_attr(
+            feature = "diesel_newtype",
+            derive(diesel::AsExpression, diesel::FromSqlRow),
+            diesel(sql_type = diesel::sql_types::Text)
+        )]
+        #[serde(try_from = "String")]
+        $(#[$attr])*
+        $vis struct $name(String);
+
+        impl $name {
+            /// Validates the value before wrapping it
+            pub fn new(value: impl Into<String>) -> Result<Self, validator::ValidationError> {
+                let value = value.into();
+                $validate(&value)?;
+                Ok(Self(value))
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = validator::ValidationError;
+
+            fn try_from(value: String) -> Result<Self, Self::Error> {
+                Self::new(value)
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = validator::ValidationError;
+
+            fn try_from(value: &str) -> Result<Self, Self::Error> {
+                Self::new(value)
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = validator::ValidationError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::new(s)
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+
+        // Implemented by hand rather than derived with `DieselNewType`,
+        // so that values read from a database are validated like any other.
+        #[cfg(feature = "diesel_newtype")]
+        impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Text, DB> for $name
+        where
+            DB: diesel::backend::Backend,
+            String: diesel::deserialize::FromSql<diesel::sql_types::Text, DB>,
+        {
+            fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+                let value = <String as diesel::deserialize::FromSql<
+                    diesel::sql_types::Text,
+                    DB,
+                >>::from_sql(bytes)?;
+                Ok(Self::try_from(value)?)
+            }
+        }
+
+        #[cfg(feature = "diesel_newtype")]
+        impl<DB> diesel::serialize::ToSql<diesel::sql_types::Text, DB> for $name
+        where
+            DB: diesel::backend::Backend,
+            str: diesel::serialize::ToSql<diesel::sql_types::Text, DB>,
+        {
+            fn to_sql<'b>(
+                &'b self,
+                out: &mut diesel::serialize::Output<'b, '_, DB>,
+            ) -> diesel::serialize::Result {
+                <str as diesel::serialize::ToSql<diesel::sql_types::Text, DB>>::to_sql(
+                    self.0.as_str(),
+                    out,
+                )
+            }
+        }
+    };
     (
         $(#[$attr:meta])*
         $vis:vis struct $name:ident($(#[$inner_attr:meta])* $inner_type:ty);
@@ -43,4 +156,24 @@ mod tests {
         let my_new_type = MyNewType("Hello".to_string());
         assert_eq!(my_new_type.to_string(), "Hello");
     }
+
+    #[test]
+    fn derive_newtype_with_validation_works() {
+        fn not_empty(value: &str) -> Result<(), validator::ValidationError> {
+            if value.is_empty() {
+                return Err(validator::ValidationError::new("empty_value"));
+            }
+            Ok(())
+        }
+
+        derive_newtype! {
+            #[validate(custom(function = not_empty))]
+            /// My validated type
+            pub struct MyValidatedType(String);
+        }
+
+        assert_eq!(MyValidatedType::new("Hello").unwrap(), "Hello");
+        assert!(MyValidatedType::new("").is_err());
+        assert!(serde_json::from_str::<MyValidatedType>(r#""""#).is_err());
+    }
 }
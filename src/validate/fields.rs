@@ -1,4 +1,5 @@
 use super::utils;
+use crate::endpoints::identifiers::CorpCodes;
 use chrono::NaiveDate;
 use std::borrow::Cow;
 use validator::ValidationError;
@@ -36,14 +37,6 @@ pub(crate) fn bsns_year(value: &str) -> Result<(), ValidationError> {
     }
 }
 
-pub(crate) fn corp_cls(value: &str) -> Result<(), ValidationError> {
-    const CORP_CLS: [&str; 4] = ["Y", "K", "N", "E"];
-    utils::contains(&CORP_CLS, value)?;
-
-    Ok(())
-}
-
-optional_string!(corp_cls);
 pub(crate) fn corp_code(value: &str) -> Result<(), ValidationError> {
     utils::is_digit(value)?;
     utils::check_string_length(value, 8, 8)?;
@@ -51,67 +44,55 @@ pub(crate) fn corp_code(value: &str) -> Result<(), ValidationError> {
     Ok(())
 }
 
-optional_string!(corp_code);
+/// Number of `corp_code`s of the endpoints which support querying multiple companies.
+pub(crate) fn corp_codes(value: &CorpCodes) -> Result<(), ValidationError> {
+    const MAX_CORP_CODES: usize = 100;
 
-pub(crate) fn crtfc_key(value: &str) -> Result<(), ValidationError> {
-    utils::check_string_length(value, 40, 40)?;
+    if value.is_empty() {
+        return Err(ValidationError::new("no_corp_codes"));
+    }
+    if value.len() > MAX_CORP_CODES {
+        let mut err = ValidationError::new("too_many_corp_codes");
+        err.add_param(Cow::from("count"), &value.len());
+        err.add_param(Cow::from("max"), &MAX_CORP_CODES);
+        return Err(err);
+    }
 
     Ok(())
 }
 
-pub(crate) fn fs_div(value: &str) -> Result<(), ValidationError> {
-    const FS_DIVS: [&str; 2] = ["CFS", "OFS"];
-    utils::contains(&FS_DIVS, value)?;
+pub(crate) fn crtfc_key(value: &str) -> Result<(), ValidationError> {
+    utils::check_string_length(value, 40, 40)?;
 
     Ok(())
 }
 
-pub(crate) fn pblntf_ty(value: &str) -> Result<(), ValidationError> {
-    const PBLNTF_TYS: [&str; 10] = ["A", "B", "C", "D", "E", "F", "G", "H", "I", "J"];
-    utils::contains(&PBLNTF_TYS, value)?;
+pub(crate) fn rcept_no(value: &str) -> Result<(), ValidationError> {
+    utils::is_digit(value)?;
+    utils::check_string_length(value, 14, 14)?;
 
     Ok(())
 }
 
-optional_string!(pblntf_ty);
-pub(crate) fn pblntf_detail_ty(value: &str) -> Result<(), ValidationError> {
-    const PBLNTF_DETAIL_TYS: [&str; 60] = [
-        "A001", "A002", "A003", "A004", "A005", "B001", "B002", "B003", "C001", "C002", "C003",
-        "C004", "C005", "C006", "C007", "C008", "C009", "C010", "C011", "D001", "D002", "D003",
-        "D004", "D005", "E001", "E002", "E003", "E004", "E005", "E006", "E007", "E008", "E009",
-        "F001", "F002", "F003", "F004", "F005", "G001", "G002", "G003", "H001", "H002", "H003",
-        "H004", "H005", "H006", "I001", "I002", "I003", "I004", "I005", "I006", "J001", "J002",
-        "J004", "J005", "J006", "J008", "J009",
+pub(crate) fn sj_div(value: &str) -> Result<(), ValidationError> {
+    const SJ_DIVS: [&str; 26] = [
+        "BS1", "BS2", "BS3", "BS4", "IS1", "IS2", "IS3", "IS4", "CIS1", "CIS2", "CIS3", "CIS4",
+        "DCIS1", "DCIS2", "DCIS3", "DCIS4", "DCIS5", "DCIS6", "DCIS7", "DCIS8", "CF1", "CF2",
+        "CF3", "CF4", "SCE1", "SCE2",
     ];
-    utils::contains(&PBLNTF_DETAIL_TYS, value)?;
-
-    Ok(())
-}
-
-optional_string!(pblntf_detail_ty);
-pub(crate) fn reprt_code(value: &str) -> Result<(), ValidationError> {
-    const REPRT_CODES: [&str; 4] = ["11013", "11012", "11014", "11011"];
-    utils::contains(&REPRT_CODES, value)?;
-
-    Ok(())
-}
-
-pub(crate) fn sort(value: &str) -> Result<(), ValidationError> {
-    const SORTS: [&str; 3] = ["date", "crp", "rpt"];
-    utils::contains(&SORTS, value)?;
+    utils::contains(&SJ_DIVS, value)?;
 
     Ok(())
 }
 
-optional_string!(sort);
-pub(crate) fn sort_mth(value: &str) -> Result<(), ValidationError> {
-    const SORT_MTHS: [&str; 2] = ["asc", "desc"];
-    utils::contains(&SORT_MTHS, value)?;
+/// 6 characters, which may include uppercase letters for the codes issued since 2024.
+pub(crate) fn stock_code(value: &str) -> Result<(), ValidationError> {
+    utils::is_uppercase_alphanumeric(value)?;
+    utils::check_string_length(value, 6, 6)?;
 
     Ok(())
 }
 
-optional_string!(sort_mth);
 pub(crate) fn yes_no(value: &str) -> Result<(), ValidationError> {
     const YES_NO: [&str; 2] = ["Y", "N"];
     utils::contains(&YES_NO, value)?;
@@ -11,6 +11,19 @@ pub(crate) fn is_digit(value: &str) -> Result<(), ValidationError> {
     }
 }
 
+pub(crate) fn is_uppercase_alphanumeric(value: &str) -> Result<(), ValidationError> {
+    if value
+        .chars()
+        .all(|c| c.is_ascii_digit() || c.is_ascii_uppercase())
+    {
+        Ok(())
+    } else {
+        let mut err = ValidationError::new("not_uppercase_alphanumeric");
+        err.add_param(Cow::from("value"), &value);
+        Err(err)
+    }
+}
+
 pub(crate) fn check_string_length(
     value: &str,
     min: usize,